@@ -1,6 +1,5 @@
 use reqwest::header::HeaderMap;
 use reqwest::multipart;
-use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::{collections::HashMap, fmt::Debug};
@@ -82,6 +81,8 @@ impl std::fmt::Display for Error {
     }
 }
 
+pub use crate::types::ValidationError;
+
 /// BotAPI allows you to interact with the Telegram Bot API.
 #[derive(Debug)]
 pub struct BotApi {
@@ -109,11 +110,10 @@ impl BotApi {
         }
     }
 
-    /// send request
-    pub async fn send<T, R>(&self, request: T) -> ReplyResult<R>
+    /// send request, deserializing the result as the method's Response type
+    pub async fn send<T>(&self, request: T) -> ReplyResult<T::Response>
     where
         T: methods::Methods,
-        R: DeserializeOwned,
     {
         if let Some(result) = self.request(&request).await?.result {
             return Ok(serde_json::from_value(result)?);
@@ -209,6 +209,14 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Use this method to send paid media. On success, the sent Message is returned.
+    pub async fn send_paid_media(
+        &self,
+        request: methods::SendPaidMedia,
+    ) -> ReplyResult<types::Message> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to send point on the map. On success, the sent Message is returned.
     pub async fn send_location(
         &self,
@@ -233,6 +241,43 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Use this method to edit text and game messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+    pub async fn edit_message_text(
+        &self,
+        request: methods::EditMessageText,
+    ) -> ReplyResult<types::MayBeMessage> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to edit captions of messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+    pub async fn edit_message_caption(
+        &self,
+        request: methods::EditMessageCaption,
+    ) -> ReplyResult<types::MayBeMessage> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to edit animation, audio, document, photo, or video messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+    pub async fn edit_message_media(
+        &self,
+        request: methods::EditMessageMedia,
+    ) -> ReplyResult<types::MayBeMessage> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to edit only the reply markup of messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+    pub async fn edit_message_reply_markup(
+        &self,
+        request: methods::EditMessageReplyMarkup,
+    ) -> ReplyResult<types::MayBeMessage> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to delete a message, including service messages. Returns True on success.
+    pub async fn delete_message(&self, request: methods::DeleteMessage) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to send information about a venue. On success, the sent Message is returned.
     pub async fn send_venue(&self, request: methods::SendVenue) -> ReplyResult<types::Message> {
         Ok(self.send(request).await?)
@@ -426,8 +471,8 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
-    /// Use this method to get up to date information about the chat (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.). Returns a Chat object on success.
-    pub async fn get_chat(&self, request: methods::GetChat) -> ReplyResult<types::Chat> {
+    /// Use this method to get up to date information about the chat (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.). Returns a ChatFullInfo object on success.
+    pub async fn get_chat(&self, request: methods::GetChat) -> ReplyResult<types::ChatFullInfo> {
         Ok(self.send(request).await?)
     }
 
@@ -455,6 +500,14 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Use this method to get the list of boosts added to a chat by a user. Requires administrator rights in the chat. Returns a UserChatBoosts object.
+    pub async fn get_user_chat_boosts(
+        &self,
+        request: methods::GetUserChatBoosts,
+    ) -> ReplyResult<types::UserChatBoosts> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to set a new group sticker set for a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
     pub async fn set_chat_sticker_set(
         &self,
@@ -471,6 +524,59 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Use this method to get custom emoji stickers, which can be used as a forum topic icon by any user. Requires no parameters. Returns an Array of Sticker objects.
+    pub async fn get_forum_topic_icon_stickers(&self) -> ReplyResult<Vec<types::Sticker>> {
+        Ok(self.send(methods::GetForumTopicIconStickers::new()).await?)
+    }
+
+    /// Use this method to edit the name of the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have can_manage_topics administrator rights. Returns True on success.
+    pub async fn edit_general_forum_topic(
+        &self,
+        request: methods::EditGeneralForumTopic,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to close an open 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns True on success.
+    pub async fn close_general_forum_topic(
+        &self,
+        request: methods::CloseGeneralForumTopic,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to reopen a closed 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically unhidden if it was hidden. Returns True on success.
+    pub async fn reopen_general_forum_topic(
+        &self,
+        request: methods::ReopenGeneralForumTopic,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to hide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically closed if it was open. Returns True on success.
+    pub async fn hide_general_forum_topic(
+        &self,
+        request: methods::HideGeneralForumTopic,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to unhide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns True on success.
+    pub async fn unhide_general_forum_topic(
+        &self,
+        request: methods::UnhideGeneralForumTopic,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to clear the list of pinned messages in a General forum topic. The bot must be an administrator in the chat for this to work and must have the can_pin_messages administrator right in the supergroup. Returns True on success.
+    pub async fn unpin_all_general_forum_topic_messages(
+        &self,
+        request: methods::UnpinAllGeneralForumTopicMessages,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to send answers to callback queries sent from inline keyboards. The answer will be displayed to the user as a notification at the top of the chat screen or as an alert. On success, True is returned.
     pub async fn answer_callback_query(
         &self,
@@ -500,6 +606,48 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Use this method to change the bot's name. Returns True on success.
+    pub async fn set_my_name(&self, request: methods::SetMyName) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to get the current bot name for the given user language. Returns BotName on success.
+    pub async fn get_my_name(&self, request: methods::GetMyName) -> ReplyResult<types::BotName> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to change the bot's description, which is shown in the chat with the bot if the chat is empty. Returns True on success.
+    pub async fn set_my_description(
+        &self,
+        request: methods::SetMyDescription,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to get the current bot description for the given user language. Returns BotDescription on success.
+    pub async fn get_my_description(
+        &self,
+        request: methods::GetMyDescription,
+    ) -> ReplyResult<types::BotDescription> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to change the bot's short description, which is shown on the bot's profile page and is sent together with the link when users share the bot. Returns True on success.
+    pub async fn set_my_short_description(
+        &self,
+        request: methods::SetMyShortDescription,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to get the current bot short description for the given user language. Returns BotShortDescription on success.
+    pub async fn get_my_short_description(
+        &self,
+        request: methods::GetMyShortDescription,
+    ) -> ReplyResult<types::BotShortDescription> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to change the bot's menu button in a private chat, or the default menu button. Returns True on success.
     pub async fn set_chat_menu_button(
         &self,
@@ -528,7 +676,7 @@ impl BotApi {
     pub async fn get_my_default_administrator_rights(
         &self,
         request: methods::GetMyDefaultAdministratorRights,
-    ) -> ReplyResult<bool> {
+    ) -> ReplyResult<types::ChatAdministratorRights> {
         Ok(self.send(request).await?)
     }
 
@@ -580,11 +728,11 @@ impl BotApi {
     pub async fn upload_sticker_file(
         &self,
         request: methods::UploadStickerFile,
-    ) -> ReplyResult<bool> {
+    ) -> ReplyResult<types::File> {
         Ok(self.send(request).await?)
     }
 
-    /// Use this method to create a new sticker set owned by a user. The bot will be able to edit the sticker set thus created. You must use exactly one of the fields png_sticker, tgs_sticker, or webm_sticker. Returns True on success.
+    /// Use this method to create a new sticker set owned by a user. The bot will be able to edit the sticker set thus created. Returns True on success.
     pub async fn create_new_sticker_set(
         &self,
         request: methods::CreateNewStickerSet,
@@ -613,6 +761,30 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Use this method to change the list of emoji assigned to a regular or custom emoji sticker. The sticker must belong to a sticker set created by the bot. Returns True on success.
+    pub async fn set_sticker_emoji_list(
+        &self,
+        request: methods::SetStickerEmojiList,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to change search keywords assigned to a regular or custom emoji sticker. The sticker must belong to a sticker set created by the bot. Returns True on success.
+    pub async fn set_sticker_keywords(
+        &self,
+        request: methods::SetStickerKeywords,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Use this method to change the mask position of a mask sticker. The sticker must belong to a sticker set that was created by the bot. Returns True on success.
+    pub async fn set_sticker_mask_position(
+        &self,
+        request: methods::SetStickerMaskPosition,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to set the thumbnail of a sticker set. Animated thumbnails can be set for animated sticker sets only. Video thumbnails can be set only for video sticker sets only. Returns True on success.
     pub async fn set_sticker_set_thumb(
         &self,
@@ -637,6 +809,22 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Stores a message that can be sent by a user of a Mini App. Returns a PreparedInlineMessage object.
+    pub async fn save_prepared_inline_message(
+        &self,
+        request: methods::SavePreparedInlineMessage,
+    ) -> ReplyResult<types::PreparedInlineMessage> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Changes the emoji status for a given user that previously allowed the bot to manage their emoji status via the Mini App method requestEmojiStatusAccess. Returns True on success.
+    pub async fn set_user_emoji_status(
+        &self,
+        request: methods::SetUserEmojiStatus,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
     /// Use this method to send invoices. On success, the sent Message is returned.
     pub async fn send_invoice(&self, request: methods::SendInvoice) -> ReplyResult<types::Message> {
         Ok(self.send(request).await?)
@@ -666,6 +854,30 @@ impl BotApi {
         Ok(self.send(request).await?)
     }
 
+    /// Refunds a successful payment in Telegram Stars. Returns True on success.
+    pub async fn refund_star_payment(
+        &self,
+        request: methods::RefundStarPayment,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Allows the bot to cancel or re-enable extension of a subscription paid in Telegram Stars. Returns True on success.
+    pub async fn edit_user_star_subscription(
+        &self,
+        request: methods::EditUserStarSubscription,
+    ) -> ReplyResult<bool> {
+        Ok(self.send(request).await?)
+    }
+
+    /// Returns the bot's Telegram Star transactions in chronological order. On success, returns a StarTransactions object.
+    pub async fn get_star_transactions(
+        &self,
+        request: methods::GetStarTransactions,
+    ) -> ReplyResult<types::StarTransactions> {
+        Ok(self.send(request).await?)
+    }
+
     /// Informs a user that some of the Telegram Passport elements they provided contains errors. The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success.
     pub async fn set_passport_data_errors(
         &self,
@@ -698,21 +910,21 @@ impl BotApi {
 
 impl BotApi {
     /// specific url
-    fn method(&self, endpoint: String) -> String {
+    fn method(&self, endpoint: &str) -> String {
         format!("{}{}/{}", self.url, self.token, endpoint)
     }
 
     /// make_request makes a request to a specific endpoint with our token.
     async fn make_request(
         &self,
-        endpoint: String,
+        endpoint: &str,
         params: types::Params,
     ) -> ReplyResult<APIResponse> {
         let mut headers = HeaderMap::new();
         headers.insert("Content-Type", "application/json".parse().unwrap());
         Ok(self
             .client
-            .post(self.method(String::from(endpoint)))
+            .post(self.method(endpoint))
             .headers(headers)
             .json(&params)
             .send()
@@ -725,16 +937,18 @@ impl BotApi {
     /// upload_files makes a request to the API with files.
     async fn upload_files(
         &self,
-        endpoint: String,
+        endpoint: &str,
         params: types::Params,
         files: HashMap<String, types::InputFile>,
     ) -> ReplyResult<APIResponse> {
         let mut form = reqwest::multipart::Form::new();
-        for (param_key, param_value) in params {
-            form = form.part(
-                param_key.to_string(),
-                multipart::Part::text(param_value.to_string()),
-            );
+        for (param_key, param_value) in types::sorted_params(&params) {
+            // strings are sent as is; other values, like reply_markup, as JSON
+            let param_value = match param_value {
+                serde_json::Value::String(text) => text,
+                value => value.to_string(),
+            };
+            form = form.part(param_key.to_string(), multipart::Part::text(param_value));
         }
         for (file_key, file_value) in files {
             match file_value.data().await? {
@@ -751,7 +965,7 @@ impl BotApi {
         }
         Ok(self
             .client
-            .post(self.method(String::from(endpoint)))
+            .post(self.method(endpoint))
             .multipart(form)
             .send()
             .await?
@@ -763,19 +977,12 @@ impl BotApi {
     /// request sends a func to Telegram, and returns the APIResponse.
     async fn request<T: methods::Methods>(&self, request: &T) -> ReplyResult<APIResponse> {
         let mut params = request.params()?;
-        if || -> bool {
-            for (_, file) in request.files() {
-                if file.need_upload() {
-                    return true;
-                }
-            }
-            false
-        }() {
-            return Ok(self
-                .upload_files(request.endpoint(), params, request.files())
-                .await?);
+        let files = request.files();
+        if files.values().any(|file| file.need_upload()) {
+            return Ok(self.upload_files(request.endpoint(), params, files).await?);
         }
-        for (key, file) in request.files() {
+        // Methods implemented outside this crate may still list file IDs and URLs in files()
+        for (key, file) in files {
             match file.data().await? {
                 types::InputFileResult::Text(text) => {
                     params.insert(key, serde_json::json!(text));
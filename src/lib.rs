@@ -1,4 +1,5 @@
 /// Telegram Bot API.
+#[cfg(feature = "reqwest-backend")]
 pub mod bot;
 /// Available methods
 pub mod methods;
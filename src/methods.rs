@@ -1,8 +1,11 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
+use subtle::ConstantTimeEq;
 
 use crate::types;
+use crate::types::ValidationError;
 
 /// request param interface
 pub trait Params {
@@ -11,10 +14,41 @@ pub trait Params {
 
 /// available methods interface
 pub trait Methods: Params {
-    fn endpoint(&self) -> String;
+    /// The type the Telegram API returns on success for this method. BotApi::send deserializes
+    /// the result into it. There is no default, so implementations outside this crate must set it.
+    type Response: DeserializeOwned;
+    fn endpoint(&self) -> &'static str;
+    /// files returns the files that need to be uploaded with multipart/form-data, keyed by field
+    /// name. Files given as a file_id or URL are not included; params() carries them instead.
     fn files(&self) -> HashMap<String, types::InputFile> {
         HashMap::new()
     }
+    /// as_webhook_reply builds the body to return from a webhook handler, making this call as
+    /// the reply to the update without an extra request. Telegram doesn't accept uploads in
+    /// webhook replies, so this fails if a file needs uploading; file IDs and URLs are fine.
+    fn as_webhook_reply(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let mut params = self.params()?;
+        for (key, file) in self.files() {
+            match file {
+                types::InputFile::FileID(text)
+                | types::InputFile::FileURL(text)
+                | types::InputFile::FileAttach(text) => {
+                    params.insert(key, serde_json::Value::String(text));
+                }
+                types::InputFile::FileBytes(_, _) | types::InputFile::FilePath(_) => {
+                    return Err(Box::new(ValidationError::new(
+                        key,
+                        "files can't be uploaded in a webhook reply".to_string(),
+                    )));
+                }
+            }
+        }
+        params.insert(
+            "method".to_string(),
+            serde_json::Value::String(self.endpoint().to_string()),
+        );
+        Ok(serde_json::Value::Object(params.into_iter().collect()))
+    }
 }
 
 /// impl params for any method
@@ -27,6 +61,173 @@ where
     }
 }
 
+/// impl_method implements Methods for a request struct from its response type and endpoint name.
+/// Methods that upload files list them as `files { "name" => field }` for InputFile fields and
+/// `optional_files { "name" => field }` for Option<InputFile> fields, keyed by the multipart field name.
+/// files() only returns the files that need uploading, so requests with only file IDs and URLs skip
+/// multipart entirely; those fields are serialized as regular params.
+macro_rules! impl_method {
+    ($name:ident, $response:ty, $endpoint:literal) => {
+        impl Methods for $name {
+            type Response = $response;
+            fn endpoint(&self) -> &'static str {
+                $endpoint
+            }
+        }
+    };
+    (
+        $name:ident,
+        $response:ty,
+        $endpoint:literal
+        $(, files { $($key:literal => $field:ident),* $(,)? })?
+        $(, optional_files { $($opt_key:literal => $opt_field:ident),* $(,)? })?
+    ) => {
+        impl Methods for $name {
+            type Response = $response;
+            fn endpoint(&self) -> &'static str {
+                $endpoint
+            }
+            fn files(&self) -> HashMap<String, types::InputFile> {
+                let files = [
+                    $($(($key, Some(&self.$field)),)*)?
+                    $($(($opt_key, self.$opt_field.as_ref()),)*)?
+                ];
+                let uploads = files
+                    .iter()
+                    .filter(|(_, file)| file.is_some_and(|file| file.need_upload()))
+                    .count();
+                if uploads == 0 {
+                    return HashMap::new();
+                }
+                let mut result = HashMap::with_capacity(uploads);
+                for (key, file) in files {
+                    if let Some(file) = file.filter(|file| file.need_upload()) {
+                        result.insert(key.to_string(), file.clone());
+                    }
+                }
+                result
+            }
+        }
+    };
+}
+
+/// skip_file_param leaves optional files out of the JSON params when they are absent or have to be
+/// uploaded; uploads are sent as multipart parts by files() instead. File IDs and URLs are sent as
+/// plain params.
+fn skip_file_param(file: &Option<types::InputFile>) -> bool {
+    file.as_ref().is_none_or(|file| file.need_upload())
+}
+
+/// chainable setters for the options shared by the send methods
+pub trait MessageOptions: Sized {
+    /// Sends the message silently. Users will receive a notification with no sound.
+    fn silent(self) -> Self;
+    /// Protects the contents of the sent message from forwarding and saving
+    fn protect(self) -> Self;
+    /// Sends the message as a reply to the message with the given ID
+    fn reply_to(self, message_id: i64) -> Self;
+    /// Attaches an inline keyboard, custom reply keyboard, keyboard removal or force reply
+    fn markup<T: Into<types::ReplyMarkup>>(self, markup: T) -> Self;
+}
+
+macro_rules! impl_message_options {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl MessageOptions for $name {
+                fn silent(mut self) -> Self {
+                    self.disable_notification = Some(true);
+                    self
+                }
+                fn protect(mut self) -> Self {
+                    self.protect_content = Some(true);
+                    self
+                }
+                fn reply_to(mut self, message_id: i64) -> Self {
+                    self.reply_to_message_id = Some(message_id);
+                    self
+                }
+                fn markup<T: Into<types::ReplyMarkup>>(mut self, markup: T) -> Self {
+                    self.reply_markup = Some(markup.into());
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_message_options!(
+    SendMessage,
+    CopyMessage,
+    SendPhoto,
+    SendAudio,
+    SendDocument,
+    SendVideo,
+    SendAnimation,
+    SendVoice,
+    SendVideoNote,
+    SendLocation,
+    SendVenue,
+    SendContact,
+    SendPoll,
+    SendDice,
+    SendSticker,
+    SendPaidMedia,
+);
+
+macro_rules! impl_caption_validate {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl $name {
+                /// validate checks the caption formatting options. BotApi::send doesn't call it, so
+                /// call it before sending the request to catch the conflict locally.
+                pub fn validate(&self) -> Result<(), ValidationError> {
+                    types::validate_caption_formatting(&self.parse_mode, &self.caption_entities)
+                }
+            }
+        )*
+    };
+}
+
+impl_caption_validate!(CopyMessage, SendVoice, SendPaidMedia, EditMessageCaption);
+
+macro_rules! impl_caption_thumb_validate {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl $name {
+                /// validate checks the caption formatting options and, for an in-memory thumbnail, the size limit.
+                /// BotApi::send doesn't call it, so call it before sending the request.
+                pub fn validate(&self) -> Result<(), ValidationError> {
+                    types::validate_caption_formatting(&self.parse_mode, &self.caption_entities)?;
+                    types::validate_thumb(&self.thumb)
+                }
+            }
+        )*
+    };
+}
+
+impl_caption_thumb_validate!(SendAudio, SendDocument, SendVideo, SendAnimation);
+
+macro_rules! impl_thumbnail {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl $name {
+                /// thumbnail returns the thumbnail, preferring `thumbnail` over the legacy `thumb` key.
+                pub fn thumbnail(&self) -> Option<&types::InputFile> {
+                    self.thumb.as_ref().or(self.legacy_thumb.as_ref())
+                }
+            }
+        )*
+    };
+}
+
+impl_thumbnail!(
+    SendAudio,
+    SendDocument,
+    SendVideo,
+    SendAnimation,
+    SendVideoNote
+);
+
 /// A simple method for testing your bot's authentication token. Requires no parameters. Returns basic information about the bot in form of a User object.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GetMe {}
@@ -36,11 +237,7 @@ impl GetMe {
     }
 }
 
-impl Methods for GetMe {
-    fn endpoint(&self) -> String {
-        "getMe".to_string()
-    }
-}
+impl_method!(GetMe, types::User, "getMe");
 
 /// Use this method to log out from the cloud Bot API server before launching the bot locally. You must log out the bot before running it locally, otherwise there is no guarantee that the bot will receive updates. After a successful call, you can immediately log in on a local server, but will not be able to log in back to the cloud Bot API server for 10 minutes. Returns True on success. Requires no parameters.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -51,11 +248,7 @@ impl LogOut {
     }
 }
 
-impl Methods for LogOut {
-    fn endpoint(&self) -> String {
-        "logOut".to_string()
-    }
-}
+impl_method!(LogOut, bool, "logOut");
 
 /// Use this method to close the bot instance before moving it from one local server to another. You need to delete the webhook before calling this method to ensure that the bot isn't launched again after server restart. The method will error 429 in the first 10 minutes after the bot is launched. Returns True on success. Requires no parameters.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -66,17 +259,16 @@ impl Close {
     }
 }
 
-impl Methods for Close {
-    fn endpoint(&self) -> String {
-        "close".to_string()
-    }
-}
+impl_method!(Close, bool, "close");
 
 /// Use this method to send text messages. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendMessage {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Text of the message to be sent, 1-4096 characters after entities parsing
     pub text: String,
     /// Mode for parsing entities in the message text. See formatting options for more details.
@@ -94,6 +286,9 @@ pub struct SendMessage {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -108,12 +303,14 @@ impl SendMessage {
     pub fn new(chat_id: types::ChatId, text: String) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             text,
             parse_mode: None,
             entities: None,
             disable_web_page_preview: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -121,17 +318,16 @@ impl SendMessage {
     }
 }
 
-impl Methods for SendMessage {
-    fn endpoint(&self) -> String {
-        "sendMessage".to_string()
-    }
-}
+impl_method!(SendMessage, types::Message, "sendMessage");
 
 /// Use this method to forward messages of any kind. Service messages can't be forwarded. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ForwardMessage {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Unique identifier for the chat where the original message was sent (or channel username in the format @channelusername)
     pub from_chat_id: types::ChatId,
     /// Sends the message silently. Users will receive a notification with no sound.
@@ -147,6 +343,7 @@ impl ForwardMessage {
     pub fn new(chat_id: types::ChatId, from_chat_id: types::ChatId, message_id: i64) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             from_chat_id,
             disable_notification: None,
             protect_content: None,
@@ -155,17 +352,16 @@ impl ForwardMessage {
     }
 }
 
-impl Methods for ForwardMessage {
-    fn endpoint(&self) -> String {
-        "forwardMessage".to_string()
-    }
-}
+impl_method!(ForwardMessage, types::Message, "forwardMessage");
 
 /// Use this method to copy messages of any kind. Service messages and invoice messages can't be copied. A quiz poll can be copied only if the value of the field correct_option_id is known to the bot. The method is analogous to the method forwardMessage, but the copied message doesn't have a link to the original message. Returns the MessageId of the sent message on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct CopyMessage {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Unique identifier for the chat where the original message was sent (or channel username in the format @channelusername)
     pub from_chat_id: types::ChatId,
     /// Message identifier in the chat specified in from_chat_id
@@ -179,6 +375,9 @@ pub struct CopyMessage {
     /// A JSON-serialized list of special entities that appear in the new caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<types::MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
@@ -199,11 +398,13 @@ impl CopyMessage {
     pub fn new(chat_id: types::ChatId, from_chat_id: types::ChatId, message_id: i64) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             from_chat_id,
             message_id,
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             disable_notification: None,
             protect_content: None,
             reply_to_message_id: None,
@@ -213,19 +414,18 @@ impl CopyMessage {
     }
 }
 
-impl Methods for CopyMessage {
-    fn endpoint(&self) -> String {
-        "copyMessage".to_string()
-    }
-}
+impl_method!(CopyMessage, types::MessageId, "copyMessage");
 
 /// Use this method to send photos. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendPhoto {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Photo to send. Pass a file_id as String to send a photo that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a photo from the Internet, or upload a new photo using multipart/form-data. The photo must be at most 10 MB in size. The photo's width and height must not exceed 10000 in total. Width and height ratio must be at most 20. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub photo: types::InputFile,
     /// Photo caption (may also be used when resending photos by file_id), 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -236,12 +436,21 @@ pub struct SendPhoto {
     /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<types::MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True if the photo needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -256,37 +465,66 @@ impl SendPhoto {
     pub fn new(chat_id: types::ChatId, photo: types::InputFile) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             photo,
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
         }
     }
-}
 
-impl Methods for SendPhoto {
-    fn endpoint(&self) -> String {
-        "sendPhoto".to_string()
-    }
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("photo".to_string(), self.photo.clone());
-        result
+    /// The photo must be at most 10 MB in size.
+    pub const MAX_PHOTO_SIZE: usize = 10 * 1024 * 1024;
+
+    /// validate checks the caption formatting options and, for in-memory photos, the size limit.
+    /// BotApi::send doesn't call it, so call it before sending the request.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        types::validate_caption_formatting(&self.parse_mode, &self.caption_entities)?;
+        self.validate_local()
+    }
+
+    /// validate_local checks the photo against the size limit before it is uploaded.
+    /// Only in-memory bytes are checked; the width, height and ratio limits would require
+    /// decoding the image and are left to the server.
+    pub fn validate_local(&self) -> Result<(), ValidationError> {
+        if let types::InputFile::FileBytes(_, bytes) = &self.photo {
+            if bytes.len() > Self::MAX_PHOTO_SIZE {
+                return Err(ValidationError::new(
+                    "photo".to_string(),
+                    format!(
+                        "photo is {} bytes, must be at most {} bytes",
+                        bytes.len(),
+                        Self::MAX_PHOTO_SIZE
+                    ),
+                ));
+            }
+        }
+        Ok(())
     }
 }
 
+impl_method!(
+    SendPhoto,
+    types::Message,
+    "sendPhoto",
+    files { "photo" => photo }
+);
+
 /// Use this method to send audio files, if you want Telegram clients to display them in the music player. Your audio must be in the .MP3 or .M4A format. On success, the sent Message is returned. Bots can currently send audio files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendAudio {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// Audio file to send. Pass a file_id as String to send an audio file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get an audio file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub audio: types::InputFile,
     /// Audio caption, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -307,14 +545,20 @@ pub struct SendAudio {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "skip_file_param")]
     pub thumb: Option<types::InputFile>,
+    /// The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<types::InputFile>,
     /// Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -337,8 +581,10 @@ impl SendAudio {
             performer: None,
             title: None,
             thumb: None,
+            legacy_thumb: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -346,31 +592,31 @@ impl SendAudio {
     }
 }
 
-impl Methods for SendAudio {
-    fn endpoint(&self) -> String {
-        "sendAudio".to_string()
-    }
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("audio".to_string(), self.audio.clone());
-        if let Some(thumb) = &self.thumb {
-            result.insert("thumb".to_string(), thumb.clone());
-        }
-        result
-    }
-}
+impl_method!(
+    SendAudio,
+    types::Message,
+    "sendAudio",
+    files { "audio" => audio },
+    optional_files { "thumbnail" => thumb }
+);
 
 /// Use this method to send general files. On success, the sent Message is returned. Bots can currently send files of any type of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendDocument {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
+    /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// File to send. Pass a file_id as String to send a file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub document: types::InputFile,
     /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "skip_file_param")]
     pub thumb: Option<types::InputFile>,
+    /// The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<types::InputFile>,
     /// Document caption (may also be used when resending documents by file_id), 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -389,6 +635,9 @@ pub struct SendDocument {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -403,14 +652,17 @@ impl SendDocument {
     pub fn new(chat_id: types::ChatId, document: types::InputFile) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             document,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
             disable_content_type_detection: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -418,20 +670,13 @@ impl SendDocument {
     }
 }
 
-impl Methods for SendDocument {
-    fn endpoint(&self) -> String {
-        "sendDocument".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("document".to_string(), self.document.clone());
-        if let Some(thumb) = &self.thumb {
-            result.insert("thumb".to_string(), thumb.clone());
-        }
-        result
-    }
-}
+impl_method!(
+    SendDocument,
+    types::Message,
+    "sendDocument",
+    files { "document" => document },
+    optional_files { "thumbnail" => thumb }
+);
 
 /// Use this method to send video files, Telegram clients support MPEG4 videos (other formats may be sent as Document). On success, the sent Message is returned. Bots can currently send video files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -439,7 +684,7 @@ pub struct SendVideo {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// Video to send. Pass a file_id as String to send a video that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a video from the Internet, or upload a new video using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub video: types::InputFile,
     /// Duration of sent video in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -451,8 +696,11 @@ pub struct SendVideo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<i64>,
     /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "skip_file_param")]
     pub thumb: Option<types::InputFile>,
+    /// The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<types::InputFile>,
     /// Video caption (may also be used when resending videos by file_id), 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -462,6 +710,12 @@ pub struct SendVideo {
     /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<types::MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True if the video needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Pass True if the uploaded video is suitable for streaming
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supports_streaming: Option<bool>,
@@ -471,6 +725,9 @@ pub struct SendVideo {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -490,12 +747,16 @@ impl SendVideo {
             width: None,
             height: None,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             supports_streaming: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -503,20 +764,13 @@ impl SendVideo {
     }
 }
 
-impl Methods for SendVideo {
-    fn endpoint(&self) -> String {
-        "sendVideo".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("video".to_string(), self.video.clone());
-        if let Some(thumb) = &self.thumb {
-            result.insert("thumb".to_string(), thumb.clone());
-        }
-        result
-    }
-}
+impl_method!(
+    SendVideo,
+    types::Message,
+    "sendVideo",
+    files { "video" => video },
+    optional_files { "thumbnail" => thumb }
+);
 
 /// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound). On success, the sent Message is returned. Bots can currently send animation files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -524,7 +778,7 @@ pub struct SendAnimation {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// Animation to send. Pass a file_id as String to send an animation that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get an animation from the Internet, or upload a new animation using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub animation: types::InputFile,
     /// Duration of sent animation in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -536,8 +790,11 @@ pub struct SendAnimation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<i64>,
     /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "skip_file_param")]
     pub thumb: Option<types::InputFile>,
+    /// The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<types::InputFile>,
     /// Animation caption (may also be used when resending animation by file_id), 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -547,12 +804,21 @@ pub struct SendAnimation {
     /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<types::MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True if the animation needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -572,11 +838,15 @@ impl SendAnimation {
             width: None,
             height: None,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -584,20 +854,13 @@ impl SendAnimation {
     }
 }
 
-impl Methods for SendAnimation {
-    fn endpoint(&self) -> String {
-        "sendAnimation".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("animation".to_string(), self.animation.clone());
-        if let Some(thumb) = &self.thumb {
-            result.insert("thumb".to_string(), thumb.clone());
-        }
-        result
-    }
-}
+impl_method!(
+    SendAnimation,
+    types::Message,
+    "sendAnimation",
+    files { "animation" => animation },
+    optional_files { "thumbnail" => thumb }
+);
 
 /// Use this method to send audio files, if you want Telegram clients to display the file as a playable voice message. For this to work, your audio must be in an .OGG file encoded with OPUS (other formats may be sent as Audio or Document). On success, the sent Message is returned. Bots can currently send voice messages of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -605,7 +868,7 @@ pub struct SendVoice {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// Audio file to send. Pass a file_id as String to send a file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub voice: types::InputFile,
     /// Voice message caption, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -625,6 +888,9 @@ pub struct SendVoice {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -646,6 +912,7 @@ impl SendVoice {
             duration: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -653,17 +920,12 @@ impl SendVoice {
     }
 }
 
-impl Methods for SendVoice {
-    fn endpoint(&self) -> String {
-        "sendVoice".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("voice".to_string(), self.voice.clone());
-        result
-    }
-}
+impl_method!(
+    SendVoice,
+    types::Message,
+    "sendVoice",
+    files { "voice" => voice }
+);
 
 /// As of v.4.0, Telegram clients support rounded square MPEG4 videos of up to 1 minute long. Use this method to send video messages. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -671,7 +933,7 @@ pub struct SendVideoNote {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// Video note to send. Pass a file_id as String to send a video note that exists on the Telegram servers (recommended) or upload a new video using multipart/form-data. More information on Sending Files ». Sending video notes by a URL is currently unsupported
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub video_note: types::InputFile,
     /// Duration of sent video in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -680,14 +942,20 @@ pub struct SendVideoNote {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub length: Option<i64>,
     /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "skip_file_param")]
     pub thumb: Option<types::InputFile>,
+    /// The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<types::InputFile>,
     /// Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -706,30 +974,32 @@ impl SendVideoNote {
             duration: None,
             length: None,
             thumb: None,
+            legacy_thumb: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
         }
     }
-}
-
-impl Methods for SendVideoNote {
-    fn endpoint(&self) -> String {
-        "sendVideoNote".to_string()
-    }
 
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("video_note".to_string(), self.video_note.clone());
-        if let Some(thumb) = &self.thumb {
-            result.insert("thumb".to_string(), thumb.clone());
-        }
-        result
+    /// validate checks an in-memory thumbnail against the size limit. Video notes must also be
+    /// square, which would require decoding the video and is left to the server. BotApi::send
+    /// doesn't call it, so call it before sending the request.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        types::validate_thumb(&self.thumb)
     }
 }
 
+impl_method!(
+    SendVideoNote,
+    types::Message,
+    "sendVideoNote",
+    files { "video_note" => video_note },
+    optional_files { "thumbnail" => thumb }
+);
+
 /// Use this method to send a group of photos, videos, documents or audios as an album. Documents and audio files can be only grouped in an album with messages of the same type. On success, an array of Messages that were sent is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendMediaGroup {
@@ -741,9 +1011,12 @@ pub struct SendMediaGroup {
     /// Sends messages silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
-    /// Protects the contents of the sent messages from forwarding and saving
+    /// Protects the contents of the sent messages from forwarding and saving. Telegram only supports this for the whole album; InputMedia items have no per-item equivalent.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the messages are a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -774,6 +1047,7 @@ impl SendMediaGroup {
             media,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
         }
@@ -781,8 +1055,9 @@ impl SendMediaGroup {
 }
 
 impl Methods for SendMediaGroup {
-    fn endpoint(&self) -> String {
-        "sendMediaGroup".to_string()
+    type Response = Vec<types::Message>;
+    fn endpoint(&self) -> &'static str {
+        "sendMediaGroup"
     }
     fn files(&self) -> HashMap<String, types::InputFile> {
         let mut result = HashMap::new();
@@ -797,6 +1072,96 @@ impl Methods for SendMediaGroup {
     }
 }
 
+/// Use this method to send paid media. On success, the sent Message is returned.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SendPaidMedia {
+    /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    pub chat_id: types::ChatId,
+    /// The number of Telegram Stars that must be paid to buy access to the media
+    pub star_count: i64,
+    /// A JSON-serialized array describing the media to be sent; up to 10 items
+    #[serde(serialize_with = "serialize_input_paid_media")]
+    pub media: Vec<types::InputPaidMedia>,
+    /// Media caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the media caption. See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<types::MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+    /// Pass True if the message should be sent even if the specified replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    /// Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<types::ReplyMarkup>,
+}
+
+/// SendPaidMedia serialize media field
+fn serialize_input_paid_media<S>(
+    input_media: &[types::InputPaidMedia],
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+    let mut seq = s.serialize_seq(Some(input_media.len()))?;
+    for (idx, elem) in input_media.iter().enumerate() {
+        seq.serialize_element(&(elem.prepare_input_paid_media_param(idx as i32)))?;
+    }
+    seq.end()
+}
+
+impl SendPaidMedia {
+    pub fn new(chat_id: types::ChatId, star_count: i64, media: Vec<types::InputPaidMedia>) -> Self {
+        Self {
+            chat_id,
+            star_count,
+            media,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            disable_notification: None,
+            protect_content: None,
+            reply_to_message_id: None,
+            allow_sending_without_reply: None,
+            reply_markup: None,
+        }
+    }
+}
+
+impl Methods for SendPaidMedia {
+    type Response = types::Message;
+    fn endpoint(&self) -> &'static str {
+        "sendPaidMedia"
+    }
+    fn files(&self) -> HashMap<String, types::InputFile> {
+        let mut result = HashMap::new();
+        for (idx, elem) in self.media.iter().enumerate() {
+            for (name, file) in elem.prepare_input_paid_media_file(idx as i32) {
+                result.insert(name, file);
+            }
+        }
+        result
+    }
+}
+
 /// Use this method to send point on the map. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendLocation {
@@ -824,6 +1189,9 @@ pub struct SendLocation {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -846,19 +1214,28 @@ impl SendLocation {
             proximity_alert_radius: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
         }
     }
-}
 
-impl Methods for SendLocation {
-    fn endpoint(&self) -> String {
-        "sendLocation".to_string()
+    /// validate checks the coordinates and live location options against the ranges accepted by the server.
+    /// BotApi::send doesn't call it, so call it before sending the request.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        types::validate_location(
+            self.latitude,
+            self.longitude,
+            self.live_period,
+            self.heading,
+            self.proximity_alert_radius,
+        )
     }
 }
 
+impl_method!(SendLocation, types::Message, "sendLocation");
+
 /// Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to stopMessageLiveLocation. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct EditMessageLiveLocation {
@@ -902,14 +1279,26 @@ impl EditMessageLiveLocation {
             reply_markup: None,
         }
     }
-}
 
-impl Methods for EditMessageLiveLocation {
-    fn endpoint(&self) -> String {
-        "editMessageLiveLocation".to_string()
+    /// validate checks the coordinates, heading and proximity alert radius against the ranges accepted by the server.
+    /// BotApi::send doesn't call it, so call it before sending the request.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        types::validate_location(
+            self.latitude,
+            self.longitude,
+            None,
+            self.heading,
+            self.proximity_alert_radius,
+        )
     }
 }
 
+impl_method!(
+    EditMessageLiveLocation,
+    types::MayBeMessage,
+    "editMessageLiveLocation"
+);
+
 /// Use this method to stop updating a live location message before live_period expires. On success, if the message is not an inline message, the edited Message is returned, otherwise True is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct StopMessageLiveLocation {
@@ -937,86 +1326,300 @@ impl StopMessageLiveLocation {
     }
 }
 
-impl Methods for StopMessageLiveLocation {
-    fn endpoint(&self) -> String {
-        "stopMessageLiveLocation".to_string()
-    }
-}
+impl_method!(
+    StopMessageLiveLocation,
+    types::MayBeMessage,
+    "stopMessageLiveLocation"
+);
 
-/// Use this method to send information about a venue. On success, the sent Message is returned.
+/// Use this method to edit text and game messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct SendVenue {
-    /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: types::ChatId,
-    /// Latitude of the venue
-    pub latitude: f64,
-    /// Longitude of the venue
-    pub longitude: f64,
-    /// Name of the venue
-    pub title: String,
-    /// Address of the venue
-    pub address: String,
-    /// Foursquare identifier of the venue
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub foursquare_id: Option<String>,
-    /// Foursquare type of the venue, if known. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+pub struct EditMessageText {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub foursquare_type: Option<String>,
-    /// Google Places identifier of the venue
+    pub business_connection_id: Option<String>,
+    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub google_place_id: Option<String>,
-    /// Google Places type of the venue. (See supported types.)
+    pub chat_id: Option<types::ChatId>,
+    /// Required if inline_message_id is not specified. Identifier of the message to edit
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub google_place_type: Option<String>,
-    /// Sends the message silently. Users will receive a notification with no sound.
+    pub message_id: Option<i64>,
+    /// Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_notification: Option<bool>,
-    /// Protects the contents of the sent message from forwarding and saving
+    pub inline_message_id: Option<String>,
+    /// New text of the message, 1-4096 characters after entities parsing
+    pub text: String,
+    /// Mode for parsing entities in the message text. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protect_content: Option<bool>,
-    /// If the message is a reply, ID of the original message
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in message text, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<i64>,
-    /// Pass True if the message should be sent even if the specified replied-to message is not found
+    pub entities: Option<Vec<types::MessageEntity>>,
+    /// Disables link previews for links in this message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_sending_without_reply: Option<bool>,
-    /// Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
+    pub disable_web_page_preview: Option<bool>,
+    /// A JSON-serialized object for a new inline keyboard.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<types::ReplyMarkup>,
+    pub reply_markup: Option<types::InlineKeyboardMarkup>,
 }
-impl SendVenue {
-    pub fn new(
-        chat_id: types::ChatId,
-        latitude: f64,
-        longitude: f64,
-        title: String,
-        address: String,
-    ) -> Self {
+impl EditMessageText {
+    pub fn new(text: String) -> Self {
         Self {
-            chat_id,
-            latitude,
-            longitude,
-            title,
-            address,
-            foursquare_id: None,
-            foursquare_type: None,
-            google_place_id: None,
-            google_place_type: None,
-            disable_notification: None,
-            protect_content: None,
-            reply_to_message_id: None,
-            allow_sending_without_reply: None,
-            reply_markup: None,
-        }
-    }
-}
+            business_connection_id: None,
+            chat_id: None,
+            message_id: None,
+            inline_message_id: None,
+            text,
+            parse_mode: None,
+            entities: None,
+            disable_web_page_preview: None,
+            reply_markup: None,
+        }
+    }
+}
+
+impl_method!(EditMessageText, types::MayBeMessage, "editMessageText");
+
+/// Use this method to edit captions of messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct EditMessageCaption {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<types::ChatId>,
+    /// Required if inline_message_id is not specified. Identifier of the message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    /// Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// New caption of the message, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the message caption. See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<types::MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media. Supported only for animation, photo and video messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// A JSON-serialized object for a new inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<types::InlineKeyboardMarkup>,
+}
+impl EditMessageCaption {
+    pub fn new() -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id: None,
+            message_id: None,
+            inline_message_id: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            reply_markup: None,
+        }
+    }
+}
+
+impl_method!(
+    EditMessageCaption,
+    types::MayBeMessage,
+    "editMessageCaption"
+);
+
+/// Use this method to edit animation, audio, document, photo, or video messages. If a message is part of a message album, then it can be edited only to an audio for audio albums, only to a document for document albums and to a photo or a video otherwise. When an inline message is edited, a new file can't be uploaded; use a previously uploaded file via its file_id or specify a URL. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EditMessageMedia {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<types::ChatId>,
+    /// Required if inline_message_id is not specified. Identifier of the message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    /// Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// A JSON-serialized object for a new media content of the message
+    #[serde(serialize_with = "serialize_edit_media")]
+    pub media: types::InputMedia,
+    /// A JSON-serialized object for a new inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<types::InlineKeyboardMarkup>,
+}
+
+fn serialize_edit_media<S>(media: &types::InputMedia, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    media.prepare_input_media_param(0).serialize(s)
+}
+
+impl EditMessageMedia {
+    pub fn new(media: types::InputMedia) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id: None,
+            message_id: None,
+            inline_message_id: None,
+            media,
+            reply_markup: None,
+        }
+    }
+}
+
+impl Methods for EditMessageMedia {
+    type Response = types::MayBeMessage;
+    fn endpoint(&self) -> &'static str {
+        "editMessageMedia"
+    }
+    fn files(&self) -> HashMap<String, types::InputFile> {
+        self.media.prepare_input_media_file(0).into_iter().collect()
+    }
+}
+
+/// Use this method to edit only the reply markup of messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct EditMessageReplyMarkup {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<types::ChatId>,
+    /// Required if inline_message_id is not specified. Identifier of the message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    /// Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// A JSON-serialized object for a new inline keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<types::InlineKeyboardMarkup>,
+}
+impl EditMessageReplyMarkup {
+    pub fn new() -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id: None,
+            message_id: None,
+            inline_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+impl_method!(
+    EditMessageReplyMarkup,
+    types::MayBeMessage,
+    "editMessageReplyMarkup"
+);
+
+/// Use this method to delete a message, including service messages. A message can only be deleted if it was sent less than 48 hours ago. Bots can delete outgoing messages in private chats, groups, and supergroups, and incoming messages in private chats. If the bot is an administrator of a group or a channel, it can delete any message there. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeleteMessage {
+    /// Unique identifier of the business connection on behalf of which the message to be deleted was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    pub chat_id: types::ChatId,
+    /// Identifier of the message to delete
+    pub message_id: i64,
+}
+impl DeleteMessage {
+    pub fn new(chat_id: types::ChatId, message_id: i64) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id,
+            message_id,
+        }
+    }
+}
 
-impl Methods for SendVenue {
-    fn endpoint(&self) -> String {
-        "sendVenue".to_string()
+impl_method!(DeleteMessage, bool, "deleteMessage");
+
+/// Use this method to send information about a venue. On success, the sent Message is returned.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SendVenue {
+    /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    pub chat_id: types::ChatId,
+    /// Latitude of the venue
+    pub latitude: f64,
+    /// Longitude of the venue
+    pub longitude: f64,
+    /// Name of the venue
+    pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    /// Foursquare type of the venue, if known. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    /// Google Places type of the venue. (See supported types.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    /// Sends the message silently. Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+    /// Pass True if the message should be sent even if the specified replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    /// Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<types::ReplyMarkup>,
+}
+impl SendVenue {
+    pub fn new(
+        chat_id: types::ChatId,
+        latitude: f64,
+        longitude: f64,
+        title: String,
+        address: String,
+    ) -> Self {
+        Self {
+            chat_id,
+            latitude,
+            longitude,
+            title,
+            address,
+            foursquare_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_to_message_id: None,
+            allow_sending_without_reply: None,
+            reply_markup: None,
+        }
     }
 }
 
+impl_method!(SendVenue, types::Message, "sendVenue");
+
 /// Use this method to send phone contacts. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendContact {
@@ -1038,6 +1641,9 @@ pub struct SendContact {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -1058,6 +1664,7 @@ impl SendContact {
             vcard: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -1065,11 +1672,7 @@ impl SendContact {
     }
 }
 
-impl Methods for SendContact {
-    fn endpoint(&self) -> String {
-        "sendContact".to_string()
-    }
-}
+impl_method!(SendContact, types::Message, "sendContact");
 
 /// Use this method to send a native poll. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1116,6 +1719,9 @@ pub struct SendPoll {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -1144,19 +1750,42 @@ impl SendPoll {
             is_closed: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
         }
     }
-}
 
-impl Methods for SendPoll {
-    fn endpoint(&self) -> String {
-        "sendPoll".to_string()
+    /// with_options builds a poll from any list of answer options, e.g. `&["Yes", "No"]`.
+    pub fn with_options<I: IntoIterator<Item = impl Into<String>>>(
+        chat_id: types::ChatId,
+        question: String,
+        options: I,
+    ) -> Self {
+        Self::new(
+            chat_id,
+            question,
+            options.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// quiz builds a quiz poll whose correct answer is the option at correct_index.
+    pub fn quiz<I: IntoIterator<Item = impl Into<String>>>(
+        chat_id: types::ChatId,
+        question: String,
+        options: I,
+        correct_index: i64,
+    ) -> Self {
+        let mut request = Self::with_options(chat_id, question, options);
+        request.type_name = Some("quiz".to_string());
+        request.correct_option_id = Some(correct_index);
+        request
     }
 }
 
+impl_method!(SendPoll, types::Message, "sendPoll");
+
 /// Use this method to send an animated emoji that will display a random value. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendDice {
@@ -1171,6 +1800,9 @@ pub struct SendDice {
     /// Protects the contents of the sent message from forwarding
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -1188,6 +1820,7 @@ impl SendDice {
             emoji: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -1195,11 +1828,7 @@ impl SendDice {
     }
 }
 
-impl Methods for SendDice {
-    fn endpoint(&self) -> String {
-        "sendDice".to_string()
-    }
-}
+impl_method!(SendDice, types::Message, "sendDice");
 
 /// Use this method when you need to tell the user that something is happening on the bot's side. The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status). Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1213,14 +1842,17 @@ impl SendChatAction {
     pub fn new(chat_id: types::ChatId, action: String) -> Self {
         Self { chat_id, action }
     }
-}
 
-impl Methods for SendChatAction {
-    fn endpoint(&self) -> String {
-        "sendChatAction".to_string()
+    /// repeat_interval returns how often to re-send a chat action while a long operation is
+    /// running. The status is cleared after about 5 seconds, so re-sending it every 4 seconds
+    /// keeps it visible without gaps; stop once the result message has been sent.
+    pub fn repeat_interval() -> std::time::Duration {
+        std::time::Duration::from_secs(4)
     }
 }
 
+impl_method!(SendChatAction, bool, "sendChatAction");
+
 /// Use this method to get a list of profile pictures for a user. Returns a UserProfilePhotos object.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GetUserProfilePhotos {
@@ -1243,11 +1875,11 @@ impl GetUserProfilePhotos {
     }
 }
 
-impl Methods for GetUserProfilePhotos {
-    fn endpoint(&self) -> String {
-        "getUserProfilePhotos".to_string()
-    }
-}
+impl_method!(
+    GetUserProfilePhotos,
+    types::UserProfilePhotos,
+    "getUserProfilePhotos"
+);
 
 /// Use this method to get basic information about a file and prepare it for downloading. For the moment, bots can download files of up to 20MB in size. On success, a File object is returned. The file can then be downloaded via the link https://api.telegram.org/file/bot<token>/<file_path>, where <file_path> is taken from the response. It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile again.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1261,11 +1893,7 @@ impl GetFile {
     }
 }
 
-impl Methods for GetFile {
-    fn endpoint(&self) -> String {
-        "getFile".to_string()
-    }
-}
+impl_method!(GetFile, types::File, "getFile");
 
 /// Use this method to ban a user in a group, a supergroup or a channel. In the case of supergroups and channels, the user will not be able to to the chat on their own using invite links, etc., unless unbanned first. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1292,11 +1920,7 @@ impl BanChatMember {
     }
 }
 
-impl Methods for BanChatMember {
-    fn endpoint(&self) -> String {
-        "banChatMember".to_string()
-    }
-}
+impl_method!(BanChatMember, bool, "banChatMember");
 
 /// Use this method to unban a previously banned user in a supergroup or channel. The user will not to the group or channel automatically, but will be able to join via link, etc. The bot must be an administrator for this to work. By default, this method guarantees that after the call the user is not a member of the chat, but will be able to join it. So if the user is a member of the chat they will also be removed from the chat. If you don't want this, use the parameter only_if_banned. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1319,11 +1943,7 @@ impl UnbanChatMember {
     }
 }
 
-impl Methods for UnbanChatMember {
-    fn endpoint(&self) -> String {
-        "unbanChatMember".to_string()
-    }
-}
+impl_method!(UnbanChatMember, bool, "unbanChatMember");
 
 /// Use this method to restrict a user in a supergroup. The bot must be an administrator in the supergroup for this to work and must have the appropriate administrator rights. Pass True for all permissions to lift restrictions from a user. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1334,6 +1954,9 @@ pub struct RestrictChatMember {
     pub user_id: i64,
     /// A JSON-serialized object for new user permissions
     pub permissions: types::ChatPermissions,
+    /// Pass True if chat permissions are set independently. Otherwise, the can_send_other_messages and can_add_web_page_previews permissions will imply the can_send_messages, can_send_audios, can_send_documents, can_send_photos, can_send_videos, can_send_video_notes, and can_send_voice_notes permissions; the can_send_polls permission will imply the can_send_messages permission.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
     /// Date when restrictions will be lifted for the user, unix time. If user is restricted for more than 366 days or less than 30 seconds from the current time, they are considered to be restricted forever
     #[serde(skip_serializing_if = "Option::is_none")]
     pub until_date: Option<i64>,
@@ -1344,16 +1967,13 @@ impl RestrictChatMember {
             chat_id,
             user_id,
             permissions,
+            use_independent_chat_permissions: None,
             until_date: None,
         }
     }
 }
 
-impl Methods for RestrictChatMember {
-    fn endpoint(&self) -> String {
-        "restrictChatMember".to_string()
-    }
-}
+impl_method!(RestrictChatMember, bool, "restrictChatMember");
 
 /// Use this method to promote or demote a user in a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Pass False for all boolean parameters to demote a user. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1414,14 +2034,33 @@ impl PromoteChatMember {
             can_pin_messages: None,
         }
     }
-}
 
-impl Methods for PromoteChatMember {
-    fn endpoint(&self) -> String {
-        "promoteChatMember".to_string()
+    /// with_rights creates a PromoteChatMember granting exactly the given rights, e.g. the rights of
+    /// another administrator converted with ChatAdministratorRights::from.
+    pub fn with_rights(
+        chat_id: types::ChatId,
+        user_id: i64,
+        rights: &types::ChatAdministratorRights,
+    ) -> Self {
+        Self {
+            is_anonymous: Some(rights.is_anonymous),
+            can_manage_chat: Some(rights.can_manage_chat),
+            can_post_messages: rights.can_post_messages,
+            can_edit_messages: rights.can_edit_messages,
+            can_delete_messages: Some(rights.can_delete_messages),
+            can_manage_video_chats: Some(rights.can_manage_video_chats),
+            can_restrict_members: Some(rights.can_restrict_members),
+            can_promote_members: Some(rights.can_promote_members),
+            can_change_info: Some(rights.can_change_info),
+            can_invite_users: Some(rights.can_invite_users),
+            can_pin_messages: rights.can_pin_messages,
+            ..Self::new(chat_id, user_id)
+        }
     }
 }
 
+impl_method!(PromoteChatMember, bool, "promoteChatMember");
+
 /// Use this method to set a custom title for an administrator in a supergroup promoted by the bot. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SetChatAdministratorCustomTitle {
@@ -1442,18 +2081,18 @@ impl SetChatAdministratorCustomTitle {
     }
 }
 
-impl Methods for SetChatAdministratorCustomTitle {
-    fn endpoint(&self) -> String {
-        "setChatAdministratorCustomTitle".to_string()
-    }
-}
+impl_method!(
+    SetChatAdministratorCustomTitle,
+    bool,
+    "setChatAdministratorCustomTitle"
+);
 
 /// Use this method to ban a channel chat in a supergroup or a channel. Until the chat is unbanned, the owner of the banned chat won't be able to send messages on behalf of any of their channels. The bot must be an administrator in the supergroup or channel for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct BanChatSenderChat {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
-    /// Unique identifier of the target sender chat
+    /// Unique identifier of the target sender chat. The API only accepts a numeric identifier here, not a @username.
     pub sender_chat_id: i64,
 }
 impl BanChatSenderChat {
@@ -1465,18 +2104,14 @@ impl BanChatSenderChat {
     }
 }
 
-impl Methods for BanChatSenderChat {
-    fn endpoint(&self) -> String {
-        "banChatSenderChat".to_string()
-    }
-}
+impl_method!(BanChatSenderChat, bool, "banChatSenderChat");
 
 /// Use this method to unban a previously banned channel chat in a supergroup or channel. The bot must be an administrator for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct UnbanChatSenderChat {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
-    /// Unique identifier of the target sender chat
+    /// Unique identifier of the target sender chat. The API only accepts a numeric identifier here, not a @username.
     pub sender_chat_id: i64,
 }
 impl UnbanChatSenderChat {
@@ -1488,11 +2123,7 @@ impl UnbanChatSenderChat {
     }
 }
 
-impl Methods for UnbanChatSenderChat {
-    fn endpoint(&self) -> String {
-        "unbanChatSenderChat".to_string()
-    }
-}
+impl_method!(UnbanChatSenderChat, bool, "unbanChatSenderChat");
 
 /// Use this method to set default chat permissions for all members. The bot must be an administrator in the group or a supergroup for this to work and must have the can_restrict_members administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1501,21 +2132,21 @@ pub struct SetChatPermissions {
     pub chat_id: types::ChatId,
     /// A JSON-serialized object for new default chat permissions
     pub permissions: types::ChatPermissions,
+    /// Pass True if chat permissions are set independently. Otherwise, the can_send_other_messages and can_add_web_page_previews permissions will imply the can_send_messages, can_send_audios, can_send_documents, can_send_photos, can_send_videos, can_send_video_notes, and can_send_voice_notes permissions; the can_send_polls permission will imply the can_send_messages permission.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
 }
 impl SetChatPermissions {
     pub fn new(chat_id: types::ChatId, permissions: types::ChatPermissions) -> Self {
         Self {
             chat_id,
             permissions,
+            use_independent_chat_permissions: None,
         }
     }
 }
 
-impl Methods for SetChatPermissions {
-    fn endpoint(&self) -> String {
-        "setChatPermissions".to_string()
-    }
-}
+impl_method!(SetChatPermissions, bool, "setChatPermissions");
 
 /// Use this method to generate a new primary invite link for a chat; any previously generated primary link is revoked. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns the new invite link as String on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1529,11 +2160,7 @@ impl ExportChatInviteLink {
     }
 }
 
-impl Methods for ExportChatInviteLink {
-    fn endpoint(&self) -> String {
-        "exportChatInviteLink".to_string()
-    }
-}
+impl_method!(ExportChatInviteLink, String, "exportChatInviteLink");
 
 /// Use this method to create an additional invite link for a chat. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. The link can be revoked using the method revokeChatInviteLink. Returns the new invite link as ChatInviteLink object.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1565,11 +2192,11 @@ impl CreateChatInviteLink {
     }
 }
 
-impl Methods for CreateChatInviteLink {
-    fn endpoint(&self) -> String {
-        "createChatInviteLink".to_string()
-    }
-}
+impl_method!(
+    CreateChatInviteLink,
+    types::ChatInviteLink,
+    "createChatInviteLink"
+);
 
 /// Use this method to edit a non-primary invite link created by the bot. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns the edited invite link as a ChatInviteLink object.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1604,11 +2231,11 @@ impl EditChatInviteLink {
     }
 }
 
-impl Methods for EditChatInviteLink {
-    fn endpoint(&self) -> String {
-        "editChatInviteLink".to_string()
-    }
-}
+impl_method!(
+    EditChatInviteLink,
+    types::ChatInviteLink,
+    "editChatInviteLink"
+);
 
 /// Use this method to revoke an invite link created by the bot. If the primary link is revoked, a new link is automatically generated. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns the revoked invite link as ChatInviteLink object.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1627,11 +2254,11 @@ impl RevokeChatInviteLink {
     }
 }
 
-impl Methods for RevokeChatInviteLink {
-    fn endpoint(&self) -> String {
-        "revokeChatInviteLink".to_string()
-    }
-}
+impl_method!(
+    RevokeChatInviteLink,
+    types::ChatInviteLink,
+    "revokeChatInviteLink"
+);
 
 /// Use this method to approve a chat join request. The bot must be an administrator in the chat for this to work and must have the can_invite_users administrator right. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1647,11 +2274,7 @@ impl ApproveChatJoinRequest {
     }
 }
 
-impl Methods for ApproveChatJoinRequest {
-    fn endpoint(&self) -> String {
-        "approveChatJoinRequest".to_string()
-    }
-}
+impl_method!(ApproveChatJoinRequest, bool, "approveChatJoinRequest");
 
 /// Use this method to decline a chat join request. The bot must be an administrator in the chat for this to work and must have the can_invite_users administrator right. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1667,11 +2290,7 @@ impl DeclineChatJoinRequest {
     }
 }
 
-impl Methods for DeclineChatJoinRequest {
-    fn endpoint(&self) -> String {
-        "declineChatJoinRequest".to_string()
-    }
-}
+impl_method!(DeclineChatJoinRequest, bool, "declineChatJoinRequest");
 
 /// Use this method to set a new profile photo for the chat. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1679,7 +2298,7 @@ pub struct SetChatPhoto {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// New chat photo, uploaded using multipart/form-data
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub photo: types::InputFile,
 }
 impl SetChatPhoto {
@@ -1688,17 +2307,12 @@ impl SetChatPhoto {
     }
 }
 
-impl Methods for SetChatPhoto {
-    fn endpoint(&self) -> String {
-        "setChatPhoto".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("photo".to_string(), self.photo.clone());
-        result
-    }
-}
+impl_method!(
+    SetChatPhoto,
+    bool,
+    "setChatPhoto",
+    files { "photo" => photo }
+);
 
 /// Use this method to delete a chat photo. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1712,11 +2326,7 @@ impl DeleteChatPhoto {
     }
 }
 
-impl Methods for DeleteChatPhoto {
-    fn endpoint(&self) -> String {
-        "deleteChatPhoto".to_string()
-    }
-}
+impl_method!(DeleteChatPhoto, bool, "deleteChatPhoto");
 
 /// Use this method to change the title of a chat. Titles can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1732,11 +2342,7 @@ impl SetChatTitle {
     }
 }
 
-impl Methods for SetChatTitle {
-    fn endpoint(&self) -> String {
-        "setChatTitle".to_string()
-    }
-}
+impl_method!(SetChatTitle, bool, "setChatTitle");
 
 /// Use this method to change the description of a group, a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1756,11 +2362,7 @@ impl SetChatDescription {
     }
 }
 
-impl Methods for SetChatDescription {
-    fn endpoint(&self) -> String {
-        "setChatDescription".to_string()
-    }
-}
+impl_method!(SetChatDescription, bool, "setChatDescription");
 
 /// Use this method to add a message to the list of pinned messages in a chat. If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' administrator right in a supergroup or 'can_edit_messages' administrator right in a channel. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1783,11 +2385,7 @@ impl PinChatMessage {
     }
 }
 
-impl Methods for PinChatMessage {
-    fn endpoint(&self) -> String {
-        "pinChatMessage".to_string()
-    }
-}
+impl_method!(PinChatMessage, bool, "pinChatMessage");
 
 /// Use this method to remove a message from the list of pinned messages in a chat. If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' administrator right in a supergroup or 'can_edit_messages' administrator right in a channel. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1807,11 +2405,7 @@ impl UnpinChatMessage {
     }
 }
 
-impl Methods for UnpinChatMessage {
-    fn endpoint(&self) -> String {
-        "unpinChatMessage".to_string()
-    }
-}
+impl_method!(UnpinChatMessage, bool, "unpinChatMessage");
 
 /// Use this method to clear the list of pinned messages in a chat. If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' administrator right in a supergroup or 'can_edit_messages' administrator right in a channel. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1825,11 +2419,7 @@ impl UnpinAllChatMessages {
     }
 }
 
-impl Methods for UnpinAllChatMessages {
-    fn endpoint(&self) -> String {
-        "unpinAllChatMessages".to_string()
-    }
-}
+impl_method!(UnpinAllChatMessages, bool, "unpinAllChatMessages");
 
 /// Use this method for your bot to leave a group, supergroup or channel. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1843,13 +2433,9 @@ impl LeaveChat {
     }
 }
 
-impl Methods for LeaveChat {
-    fn endpoint(&self) -> String {
-        "leaveChat".to_string()
-    }
-}
+impl_method!(LeaveChat, bool, "leaveChat");
 
-/// Use this method to get up to date information about the chat (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.). Returns a Chat object on success.
+/// Use this method to get up to date information about the chat (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.). Returns a ChatFullInfo object on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GetChat {
     /// Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
@@ -1861,11 +2447,7 @@ impl GetChat {
     }
 }
 
-impl Methods for GetChat {
-    fn endpoint(&self) -> String {
-        "getChat".to_string()
-    }
-}
+impl_method!(GetChat, types::ChatFullInfo, "getChat");
 
 /// Use this method to get a list of administrators in a chat, which aren't bots. Returns an Array of ChatMember objects.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1879,11 +2461,11 @@ impl GetChatAdministrators {
     }
 }
 
-impl Methods for GetChatAdministrators {
-    fn endpoint(&self) -> String {
-        "getChatAdministrators".to_string()
-    }
-}
+impl_method!(
+    GetChatAdministrators,
+    Vec<types::ChatMember>,
+    "getChatAdministrators"
+);
 
 /// Use this method to get the number of members in a chat. Returns Int on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1897,11 +2479,7 @@ impl GetChatMemberCount {
     }
 }
 
-impl Methods for GetChatMemberCount {
-    fn endpoint(&self) -> String {
-        "getChatMemberCount".to_string()
-    }
-}
+impl_method!(GetChatMemberCount, i64, "getChatMemberCount");
 
 /// Use this method to get information about a member of a chat. Returns a ChatMember object on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1917,12 +2495,28 @@ impl GetChatMember {
     }
 }
 
-impl Methods for GetChatMember {
-    fn endpoint(&self) -> String {
-        "getChatMember".to_string()
+impl_method!(GetChatMember, types::ChatMember, "getChatMember");
+
+/// Use this method to get the list of boosts added to a chat by a user. Requires administrator rights in the chat. Returns a UserChatBoosts object.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetUserChatBoosts {
+    /// Unique identifier for the chat or username of the channel (in the format @channelusername)
+    pub chat_id: types::ChatId,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+impl GetUserChatBoosts {
+    pub fn new(chat_id: types::ChatId, user_id: i64) -> Self {
+        Self { chat_id, user_id }
     }
 }
 
+impl_method!(
+    GetUserChatBoosts,
+    types::UserChatBoosts,
+    "getUserChatBoosts"
+);
+
 /// Use this method to set a new group sticker set for a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SetChatStickerSet {
@@ -1940,11 +2534,7 @@ impl SetChatStickerSet {
     }
 }
 
-impl Methods for SetChatStickerSet {
-    fn endpoint(&self) -> String {
-        "setChatStickerSet".to_string()
-    }
-}
+impl_method!(SetChatStickerSet, bool, "setChatStickerSet");
 
 /// Use this method to delete a group sticker set from a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1958,12 +2548,113 @@ impl DeleteChatStickerSet {
     }
 }
 
-impl Methods for DeleteChatStickerSet {
-    fn endpoint(&self) -> String {
-        "deleteChatStickerSet".to_string()
+impl_method!(DeleteChatStickerSet, bool, "deleteChatStickerSet");
+
+/// Use this method to get custom emoji stickers, which can be used as a forum topic icon by any user. Requires no parameters. Returns an Array of Sticker objects.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GetForumTopicIconStickers {}
+impl GetForumTopicIconStickers {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl_method!(
+    GetForumTopicIconStickers,
+    Vec<types::Sticker>,
+    "getForumTopicIconStickers"
+);
+
+/// Use this method to edit the name of the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have can_manage_topics administrator rights. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EditGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
+    pub chat_id: types::ChatId,
+    /// New topic name, 1-128 characters
+    pub name: String,
+}
+impl EditGeneralForumTopic {
+    pub fn new(chat_id: types::ChatId, name: String) -> Self {
+        Self { chat_id, name }
+    }
+}
+
+impl_method!(EditGeneralForumTopic, bool, "editGeneralForumTopic");
+
+/// Use this method to close an open 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CloseGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
+    pub chat_id: types::ChatId,
+}
+impl CloseGeneralForumTopic {
+    pub fn new(chat_id: types::ChatId) -> Self {
+        Self { chat_id }
+    }
+}
+
+impl_method!(CloseGeneralForumTopic, bool, "closeGeneralForumTopic");
+
+/// Use this method to reopen a closed 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically unhidden if it was hidden. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReopenGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
+    pub chat_id: types::ChatId,
+}
+impl ReopenGeneralForumTopic {
+    pub fn new(chat_id: types::ChatId) -> Self {
+        Self { chat_id }
+    }
+}
+
+impl_method!(ReopenGeneralForumTopic, bool, "reopenGeneralForumTopic");
+
+/// Use this method to hide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically closed if it was open. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct HideGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
+    pub chat_id: types::ChatId,
+}
+impl HideGeneralForumTopic {
+    pub fn new(chat_id: types::ChatId) -> Self {
+        Self { chat_id }
+    }
+}
+
+impl_method!(HideGeneralForumTopic, bool, "hideGeneralForumTopic");
+
+/// Use this method to unhide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UnhideGeneralForumTopic {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
+    pub chat_id: types::ChatId,
+}
+impl UnhideGeneralForumTopic {
+    pub fn new(chat_id: types::ChatId) -> Self {
+        Self { chat_id }
+    }
+}
+
+impl_method!(UnhideGeneralForumTopic, bool, "unhideGeneralForumTopic");
+
+/// Use this method to clear the list of pinned messages in a General forum topic. The bot must be an administrator in the chat for this to work and must have the can_pin_messages administrator right in the supergroup. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UnpinAllGeneralForumTopicMessages {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
+    pub chat_id: types::ChatId,
+}
+impl UnpinAllGeneralForumTopicMessages {
+    pub fn new(chat_id: types::ChatId) -> Self {
+        Self { chat_id }
     }
 }
 
+impl_method!(
+    UnpinAllGeneralForumTopicMessages,
+    bool,
+    "unpinAllGeneralForumTopicMessages"
+);
+
 /// Use this method to send answers to callback queries sent from inline keyboards. The answer will be displayed to the user as a notification at the top of the chat screen or as an alert. On success, True is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct AnswerCallbackQuery {
@@ -1988,14 +2679,36 @@ impl AnswerCallbackQuery {
             url: None,
         }
     }
-}
 
-impl Methods for AnswerCallbackQuery {
-    fn endpoint(&self) -> String {
-        "answerCallbackQuery".to_string()
+    /// alert answers the query with an alert the user has to dismiss.
+    pub fn alert(callback_query_id: String, text: String) -> Self {
+        Self {
+            text: Some(text),
+            show_alert: Some(true),
+            ..Self::new(callback_query_id)
+        }
+    }
+
+    /// toast answers the query with a notification at the top of the chat screen.
+    pub fn toast(callback_query_id: String, text: String) -> Self {
+        Self {
+            text: Some(text),
+            ..Self::new(callback_query_id)
+        }
+    }
+
+    /// game_url opens url in the user's client. Only valid for callback_game buttons and t.me
+    /// links that open the bot, so text and show_alert are left unset.
+    pub fn game_url(callback_query_id: String, url: String) -> Self {
+        Self {
+            url: Some(url),
+            ..Self::new(callback_query_id)
+        }
     }
 }
 
+impl_method!(AnswerCallbackQuery, bool, "answerCallbackQuery");
+
 /// Use this method to change the list of the bot's commands. See https://core.telegram.org/bots#commands for more details about bot commands. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SetMyCommands {
@@ -2018,66 +2731,172 @@ impl SetMyCommands {
     }
 }
 
-impl Methods for SetMyCommands {
-    fn endpoint(&self) -> String {
-        "setMyCommands".to_string()
-    }
-}
+impl_method!(SetMyCommands, bool, "setMyCommands");
+
+/// Use this method to delete the list of the bot's commands for the given scope and user language. After deletion, higher level commands will be shown to affected users. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeleteMyCommands {
+    /// A JSON-serialized object, describing scope of users for which the commands are relevant. Defaults to BotCommandScopeDefault.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<types::BotCommandScope>,
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users from the given scope, for whose language there are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+impl DeleteMyCommands {
+    pub fn new() -> Self {
+        Self {
+            scope: None,
+            language_code: None,
+        }
+    }
+}
+
+impl_method!(DeleteMyCommands, bool, "deleteMyCommands");
+
+/// Use this method to get the current list of the bot's commands for the given scope and user language. Returns an Array of BotCommand objects. If commands aren't set, an empty list is returned.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetMyCommands {
+    /// A JSON-serialized object, describing scope of users. Defaults to BotCommandScopeDefault.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<types::BotCommandScope>,
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+impl GetMyCommands {
+    pub fn new() -> Self {
+        Self {
+            scope: None,
+            language_code: None,
+        }
+    }
+}
+
+impl_method!(GetMyCommands, Vec<types::BotCommand>, "getMyCommands");
+
+/// Use this method to change the bot's name. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SetMyName {
+    /// New bot name; 0-64 characters. Pass an empty string to remove the dedicated name for the given language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A two-letter ISO 639-1 language code. If empty, the name will be shown to all users for whose language there is no dedicated name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+impl SetMyName {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            language_code: None,
+        }
+    }
+}
+
+impl_method!(SetMyName, bool, "setMyName");
+
+/// Use this method to get the current bot name for the given user language. Returns BotName on success.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GetMyName {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+impl GetMyName {
+    pub fn new() -> Self {
+        Self {
+            language_code: None,
+        }
+    }
+}
+
+impl_method!(GetMyName, types::BotName, "getMyName");
+
+/// Use this method to change the bot's description, which is shown in the chat with the bot if the chat is empty. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SetMyDescription {
+    /// New bot description; 0-512 characters. Pass an empty string to remove the dedicated description for the given language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A two-letter ISO 639-1 language code. If empty, the description will be applied to all users for whose language there is no dedicated description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+impl SetMyDescription {
+    pub fn new() -> Self {
+        Self {
+            description: None,
+            language_code: None,
+        }
+    }
+}
+
+impl_method!(SetMyDescription, bool, "setMyDescription");
+
+/// Use this method to get the current bot description for the given user language. Returns BotDescription on success.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GetMyDescription {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+impl GetMyDescription {
+    pub fn new() -> Self {
+        Self {
+            language_code: None,
+        }
+    }
+}
+
+impl_method!(GetMyDescription, types::BotDescription, "getMyDescription");
 
-/// Use this method to delete the list of the bot's commands for the given scope and user language. After deletion, higher level commands will be shown to affected users. Returns True on success.
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct DeleteMyCommands {
-    /// A JSON-serialized object, describing scope of users for which the commands are relevant. Defaults to BotCommandScopeDefault.
+/// Use this method to change the bot's short description, which is shown on the bot's profile page and is sent together with the link when users share the bot. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SetMyShortDescription {
+    /// New short description for the bot; 0-120 characters. Pass an empty string to remove the dedicated short description for the given language.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<types::BotCommandScope>,
-    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users from the given scope, for whose language there are no dedicated commands
+    pub short_description: Option<String>,
+    /// A two-letter ISO 639-1 language code. If empty, the short description will be applied to all users for whose language there is no dedicated short description.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
-impl DeleteMyCommands {
+impl SetMyShortDescription {
     pub fn new() -> Self {
         Self {
-            scope: None,
+            short_description: None,
             language_code: None,
         }
     }
 }
 
-impl Methods for DeleteMyCommands {
-    fn endpoint(&self) -> String {
-        "deleteMyCommands".to_string()
-    }
-}
+impl_method!(SetMyShortDescription, bool, "setMyShortDescription");
 
-/// Use this method to get the current list of the bot's commands for the given scope and user language. Returns an Array of BotCommand objects. If commands aren't set, an empty list is returned.
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct GetMyCommands {
-    /// A JSON-serialized object, describing scope of users. Defaults to BotCommandScopeDefault.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<types::BotCommandScope>,
+/// Use this method to get the current bot short description for the given user language. Returns BotShortDescription on success.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GetMyShortDescription {
     /// A two-letter ISO 639-1 language code or an empty string
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
-impl GetMyCommands {
+impl GetMyShortDescription {
     pub fn new() -> Self {
         Self {
-            scope: None,
             language_code: None,
         }
     }
 }
 
-impl Methods for GetMyCommands {
-    fn endpoint(&self) -> String {
-        "getMyCommands".to_string()
-    }
-}
+impl_method!(
+    GetMyShortDescription,
+    types::BotShortDescription,
+    "getMyShortDescription"
+);
 
 /// Use this method to change the bot's menu button in a private chat, or the default menu button. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SetChatMenuButton {
-    /// Unique identifier for the target private chat. If not specified, default bot's menu button will be changed
+    /// Unique identifier for the target private chat. If not specified, default bot's menu button will be changed. Menu buttons only exist in private chats, which have no @username form, so this is numeric.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
     /// A JSON-serialized object for the bot's new menu button. Defaults to MenuButtonDefault
@@ -2093,16 +2912,12 @@ impl SetChatMenuButton {
     }
 }
 
-impl Methods for SetChatMenuButton {
-    fn endpoint(&self) -> String {
-        "setChatMenuButton".to_string()
-    }
-}
+impl_method!(SetChatMenuButton, bool, "setChatMenuButton");
 
 /// Use this method to get the current value of the bot's menu button in a private chat, or the default menu button. Returns MenuButton on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GetChatMenuButton {
-    /// Unique identifier for the target private chat. If not specified, default bot's menu button will be returned
+    /// Unique identifier for the target private chat. If not specified, default bot's menu button will be returned. Menu buttons only exist in private chats, which have no @username form, so this is numeric.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
 }
@@ -2112,11 +2927,7 @@ impl GetChatMenuButton {
     }
 }
 
-impl Methods for GetChatMenuButton {
-    fn endpoint(&self) -> String {
-        "getChatMenuButton".to_string()
-    }
-}
+impl_method!(GetChatMenuButton, types::MenuButton, "getChatMenuButton");
 
 /// Use this method to change the default administrator rights requested by the bot when it's added as an administrator to groups or channels. These rights will be suggested to users, but they are are free to modify the list before adding the bot. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2137,11 +2948,11 @@ impl SetMyDefaultAdministratorRights {
     }
 }
 
-impl Methods for SetMyDefaultAdministratorRights {
-    fn endpoint(&self) -> String {
-        "setMyDefaultAdministratorRights".to_string()
-    }
-}
+impl_method!(
+    SetMyDefaultAdministratorRights,
+    bool,
+    "setMyDefaultAdministratorRights"
+);
 
 /// Use this method to get the current default administrator rights of the bot. Returns ChatAdministratorRights on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2156,11 +2967,11 @@ impl GetMyDefaultAdministratorRights {
     }
 }
 
-impl Methods for GetMyDefaultAdministratorRights {
-    fn endpoint(&self) -> String {
-        "getMyDefaultAdministratorRights".to_string()
-    }
-}
+impl_method!(
+    GetMyDefaultAdministratorRights,
+    types::ChatAdministratorRights,
+    "getMyDefaultAdministratorRights"
+);
 
 /// Use this method to receive incoming updates using long polling (wiki). Returns an Array of Update objects.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2187,11 +2998,76 @@ impl GetUpdates {
             allowed_updates: None,
         }
     }
+
+    /// long_poll creates a GetUpdates that waits up to timeout_secs for new updates.
+    /// The HTTP client's read timeout must be longer than timeout_secs, otherwise the
+    /// request is aborted mid-poll; allow at least timeout_secs plus a few seconds, see
+    /// recommended_client_timeout.
+    pub fn long_poll(timeout_secs: i64) -> Self {
+        Self {
+            timeout: Some(timeout_secs),
+            ..Self::new()
+        }
+    }
+
+    /// recommended_client_timeout returns the minimum HTTP read timeout to use for this
+    /// request: the long polling timeout plus a 5 second margin for network latency.
+    pub fn recommended_client_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout.unwrap_or(0).max(0) as u64 + 5)
+    }
+}
+
+impl_method!(GetUpdates, Vec<types::Update>, "getUpdates");
+
+/// next_offset returns the offset that confirms all the given updates: one more than the highest
+/// update_id, or None if there are no updates.
+pub fn next_offset(updates: &[types::Update]) -> Option<i64> {
+    updates.iter().map(|update| update.update_id + 1).max()
 }
 
-impl Methods for GetUpdates {
-    fn endpoint(&self) -> String {
-        "getUpdates".to_string()
+/// UpdatesState holds the state of a long polling loop, independent of the HTTP client and runtime:
+/// build each request with next_request and pass the received updates to ingest.
+#[derive(Debug, Clone, Default)]
+pub struct UpdatesState {
+    /// The offset sent with the next request
+    pub offset: Option<i64>,
+    /// Timeout in seconds for long polling
+    pub timeout: Option<i64>,
+    /// The update types to receive
+    pub allowed_updates: Option<Vec<String>>,
+}
+
+impl UpdatesState {
+    pub fn new() -> Self {
+        Self {
+            offset: None,
+            timeout: None,
+            allowed_updates: None,
+        }
+    }
+
+    /// next_request creates the GetUpdates for the next poll.
+    pub fn next_request(&self) -> GetUpdates {
+        GetUpdates {
+            offset: self.offset,
+            timeout: self.timeout,
+            allowed_updates: self.allowed_updates.clone(),
+            ..GetUpdates::new()
+        }
+    }
+
+    /// ingest advances the offset past the received updates and returns the ones not seen before,
+    /// dropping any that were already confirmed by an earlier offset.
+    pub fn ingest<'a>(&mut self, updates: &'a [types::Update]) -> Vec<&'a types::Update> {
+        let offset = self.offset.unwrap_or(i64::MIN);
+        let fresh = updates
+            .iter()
+            .filter(|update| update.update_id >= offset)
+            .collect();
+        if let Some(next) = next_offset(updates) {
+            self.offset = Some(next.max(offset));
+        }
+        fresh
     }
 }
 
@@ -2201,7 +3077,7 @@ pub struct SetWebhook {
     /// HTTPS URL to send updates to. Use an empty string to remove webhook integration
     pub url: String,
     /// Upload your public key certificate so that the root certificate in use can be checked. See our self-signed guide for details.
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "skip_file_param")]
     pub certificate: Option<types::InputFile>,
     /// The fixed IP address which will be used to send webhook requests instead of the IP address resolved through DNS
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2231,20 +3107,62 @@ impl SetWebhook {
             secret_token: None,
         }
     }
-}
 
-impl Methods for SetWebhook {
-    fn endpoint(&self) -> String {
-        "setWebhook".to_string()
+    /// validate checks that secret_token is 1-256 characters from A-Z, a-z, 0-9, _ and -. The
+    /// character set is checked first, so the length is counted in ASCII characters. BotApi::send
+    /// doesn't call it, so call it before sending the request.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(secret_token) = &self.secret_token {
+            if let Some(c) = secret_token
+                .chars()
+                .find(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == '-'))
+            {
+                return Err(ValidationError::new(
+                    "secret_token".to_string(),
+                    format!(
+                        "secret_token contains {:?}, only A-Z, a-z, 0-9, _ and - are allowed",
+                        c
+                    ),
+                ));
+            }
+            if secret_token.is_empty() || secret_token.len() > 256 {
+                return Err(ValidationError::new(
+                    "secret_token".to_string(),
+                    format!(
+                        "secret_token is {} characters, must be between 1 and 256",
+                        secret_token.len()
+                    ),
+                ));
+            }
+        }
+        Ok(())
     }
+}
 
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        if let Some(certificate) = &self.certificate {
-            result.insert("certificate".to_string(), certificate.clone());
-        }
-        result
+impl_method!(
+    SetWebhook,
+    bool,
+    "setWebhook",
+    optional_files { "certificate" => certificate }
+);
+
+/// The header Telegram sends the SetWebhook secret_token in with every webhook request.
+pub const WEBHOOK_SECRET_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// verify_webhook_secret reports whether header_value, the WEBHOOK_SECRET_HEADER of an incoming
+/// webhook request, matches the configured secret_token. The bytes are compared with
+/// subtle::ConstantTimeEq so the secret can't be guessed from response timings. A header of a
+/// different length is rejected before any byte is compared, so timings do reveal the length
+/// of the secret. A missing header or an empty configured secret never matches.
+pub fn verify_webhook_secret(configured: &str, header_value: Option<&str>) -> bool {
+    let header_value = match header_value {
+        Some(header_value) => header_value,
+        None => return false,
+    };
+    if configured.is_empty() {
+        return false;
     }
+    configured.as_bytes().ct_eq(header_value.as_bytes()).into()
 }
 
 /// Use this method to remove webhook integration if you decide to switch back to getUpdates. Returns True on success.
@@ -2262,11 +3180,7 @@ impl DeleteWebhook {
     }
 }
 
-impl Methods for DeleteWebhook {
-    fn endpoint(&self) -> String {
-        "deleteWebhook".to_string()
-    }
-}
+impl_method!(DeleteWebhook, bool, "deleteWebhook");
 
 /// Use this method to get current webhook status. Requires no parameters. On success, returns a WebhookInfo object. If the bot is using getUpdates, will return an object with the url field empty.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2277,11 +3191,7 @@ impl GetWebhookInfo {
     }
 }
 
-impl Methods for GetWebhookInfo {
-    fn endpoint(&self) -> String {
-        "getWebhookInfo".to_string()
-    }
-}
+impl_method!(GetWebhookInfo, types::WebhookInfo, "getWebhookInfo");
 
 /// Use this method to send static .WEBP, animated .TGS, or video .WEBM stickers. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2289,7 +3199,7 @@ pub struct SendSticker {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: types::ChatId,
     /// Sticker to send. Pass a file_id as String to send a file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a .WEBP file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub sticker: types::InputFile,
     /// Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2297,6 +3207,9 @@ pub struct SendSticker {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -2314,6 +3227,7 @@ impl SendSticker {
             sticker,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -2321,17 +3235,12 @@ impl SendSticker {
     }
 }
 
-impl Methods for SendSticker {
-    fn endpoint(&self) -> String {
-        "sendSticker".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("sticker".to_string(), self.sticker.clone());
-        result
-    }
-}
+impl_method!(
+    SendSticker,
+    types::Message,
+    "sendSticker",
+    files { "sticker" => sticker }
+);
 
 /// Use this method to get a sticker set. On success, a StickerSet object is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2345,11 +3254,7 @@ impl GetStickerSet {
     }
 }
 
-impl Methods for GetStickerSet {
-    fn endpoint(&self) -> String {
-        "getStickerSet".to_string()
-    }
-}
+impl_method!(GetStickerSet, types::StickerSet, "getStickerSet");
 
 /// Use this method to get information about custom emoji stickers by their identifiers. Returns an Array of Sticker objects.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2363,11 +3268,11 @@ impl GetCustomEmojiStickers {
     }
 }
 
-impl Methods for GetCustomEmojiStickers {
-    fn endpoint(&self) -> String {
-        "getCustomEmojiStickers".to_string()
-    }
-}
+impl_method!(
+    GetCustomEmojiStickers,
+    Vec<types::Sticker>,
+    "getCustomEmojiStickers"
+);
 
 /// Use this method to upload a .PNG file with a sticker for later use in createNewStickerSet and addStickerToSet methods (can be used multiple times). Returns the uploaded File on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2375,7 +3280,7 @@ pub struct UploadStickerFile {
     /// User identifier of sticker file owner
     pub user_id: i64,
     /// PNG image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "types::InputFile::need_upload")]
     pub png_sticker: types::InputFile,
 }
 impl UploadStickerFile {
@@ -2387,19 +3292,14 @@ impl UploadStickerFile {
     }
 }
 
-impl Methods for UploadStickerFile {
-    fn endpoint(&self) -> String {
-        "uploadStickerFile".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        result.insert("png_sticker".to_string(), self.png_sticker.clone());
-        result
-    }
-}
+impl_method!(
+    UploadStickerFile,
+    types::File,
+    "uploadStickerFile",
+    files { "png_sticker" => png_sticker }
+);
 
-/// Use this method to create a new sticker set owned by a user. The bot will be able to edit the sticker set thus created. You must use exactly one of the fields png_sticker, tgs_sticker, or webm_sticker. Returns True on success.
+/// Use this method to create a new sticker set owned by a user. The bot will be able to edit the sticker set thus created. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct CreateNewStickerSet {
     /// User identifier of created sticker set owner
@@ -2408,55 +3308,63 @@ pub struct CreateNewStickerSet {
     pub name: String,
     /// Sticker set title, 1-64 characters
     pub title: String,
-    /// PNG image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
-    pub png_sticker: Option<types::InputFile>,
-    /// TGS animation with the sticker, uploaded using multipart/form-data. See https://core.telegram.org/stickers#animated-sticker-requirements for technical requirements
-    #[serde(skip_serializing)]
-    pub tgs_sticker: Option<types::InputFile>,
-    /// WEBM video with the sticker, uploaded using multipart/form-data. See https://core.telegram.org/stickers#video-sticker-requirements for technical requirements
-    #[serde(skip_serializing)]
-    pub webm_sticker: Option<types::InputFile>,
-    /// Type of stickers in the set, pass “regular” or “mask”. Custom emoji sticker sets can't be created via the Bot API at the moment. By default, a regular sticker set is created.
+    /// A JSON-serialized list of 1-50 initial stickers to be added to the sticker set
+    #[serde(serialize_with = "serialize_input_stickers")]
+    pub stickers: Vec<types::InputSticker>,
+    /// Type of stickers in the set, pass “regular”, “mask”, or “custom_emoji”. By default, a regular sticker set is created.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker_type: Option<String>,
-    /// One or more emoji corresponding to the sticker
-    pub emojis: String,
-    /// A JSON-serialized object for position where the mask should be placed on faces
+    /// Pass True if stickers in the sticker set must be repainted to the color of text when used in messages, the accent color if used as emoji status, white on chat photos, or another appropriate color based on context; for custom emoji sticker sets only
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mask_position: Option<types::MaskPosition>,
+    pub needs_repainting: Option<bool>,
+}
+
+/// CreateNewStickerSet serialize stickers field
+fn serialize_input_stickers<S>(stickers: &[types::InputSticker], s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+    let mut seq = s.serialize_seq(Some(stickers.len()))?;
+    for (idx, sticker) in stickers.iter().enumerate() {
+        seq.serialize_element(&sticker.prepare_input_sticker_param(idx))?;
+    }
+    seq.end()
 }
+
 impl CreateNewStickerSet {
-    pub fn new(user_id: i64, name: String, title: String, emojis: String) -> Self {
+    pub fn new(
+        user_id: i64,
+        name: String,
+        title: String,
+        stickers: Vec<types::InputSticker>,
+    ) -> Self {
         Self {
             user_id,
             name,
             title,
-            png_sticker: None,
-            tgs_sticker: None,
-            webm_sticker: None,
+            stickers,
             sticker_type: None,
-            emojis,
-            mask_position: None,
+            needs_repainting: None,
         }
     }
 }
 
 impl Methods for CreateNewStickerSet {
-    fn endpoint(&self) -> String {
-        "createNewStickerSet".to_string()
+    type Response = bool;
+    fn endpoint(&self) -> &'static str {
+        "createNewStickerSet"
     }
 
     fn files(&self) -> HashMap<String, types::InputFile> {
         let mut result = HashMap::new();
-        if let Some(png_sticker) = &self.png_sticker {
-            result.insert("png_sticker".to_string(), png_sticker.clone());
-        }
-        if let Some(tgs_sticker) = &self.tgs_sticker {
-            result.insert("tgs_sticker".to_string(), tgs_sticker.clone());
-        }
-        if let Some(webm_sticker) = &self.webm_sticker {
-            result.insert("webm_sticker".to_string(), webm_sticker.clone());
+        for (idx, sticker) in self.stickers.iter().enumerate() {
+            if sticker.sticker.need_upload() {
+                result.insert(
+                    types::InputSticker::attach_file_name(idx),
+                    sticker.sticker.clone(),
+                );
+            }
         }
         result
     }
@@ -2470,13 +3378,13 @@ pub struct AddStickerToSet {
     /// Sticker set name
     pub name: String,
     /// PNG image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files »
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "skip_file_param")]
     pub png_sticker: Option<types::InputFile>,
     /// TGS animation with the sticker, uploaded using multipart/form-data. See https://core.telegram.org/stickers#animated-sticker-requirements for technical requirements
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "skip_file_param")]
     pub tgs_sticker: Option<types::InputFile>,
     /// WEBM video with the sticker, uploaded using multipart/form-data. See https://core.telegram.org/stickers#video-sticker-requirements for technical requirements
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "skip_file_param")]
     pub webm_sticker: Option<types::InputFile>,
     /// One or more emoji corresponding to the sticker
     pub emojis: String,
@@ -2498,25 +3406,16 @@ impl AddStickerToSet {
     }
 }
 
-impl Methods for AddStickerToSet {
-    fn endpoint(&self) -> String {
-        "addStickerToSet".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        if let Some(png_sticker) = &self.png_sticker {
-            result.insert("png_sticker".to_string(), png_sticker.clone());
-        }
-        if let Some(tgs_sticker) = &self.tgs_sticker {
-            result.insert("tgs_sticker".to_string(), tgs_sticker.clone());
-        }
-        if let Some(webm_sticker) = &self.webm_sticker {
-            result.insert("webm_sticker".to_string(), webm_sticker.clone());
-        }
-        result
+impl_method!(
+    AddStickerToSet,
+    bool,
+    "addStickerToSet",
+    optional_files {
+        "png_sticker" => png_sticker,
+        "tgs_sticker" => tgs_sticker,
+        "webm_sticker" => webm_sticker
     }
-}
+);
 
 /// Use this method to move a sticker in a set created by the bot to a specific position. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2532,11 +3431,7 @@ impl SetStickerPositionInSet {
     }
 }
 
-impl Methods for SetStickerPositionInSet {
-    fn endpoint(&self) -> String {
-        "setStickerPositionInSet".to_string()
-    }
-}
+impl_method!(SetStickerPositionInSet, bool, "setStickerPositionInSet");
 
 /// Use this method to delete a sticker from a set created by the bot. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2550,12 +3445,67 @@ impl DeleteStickerFromSet {
     }
 }
 
-impl Methods for DeleteStickerFromSet {
-    fn endpoint(&self) -> String {
-        "deleteStickerFromSet".to_string()
+impl_method!(DeleteStickerFromSet, bool, "deleteStickerFromSet");
+
+/// Use this method to change the list of emoji assigned to a regular or custom emoji sticker. The sticker must belong to a sticker set created by the bot. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SetStickerEmojiList {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A JSON-serialized list of 1-20 emoji associated with the sticker
+    pub emoji_list: Vec<String>,
+}
+impl SetStickerEmojiList {
+    pub fn new(sticker: String, emoji_list: Vec<String>) -> Self {
+        Self {
+            sticker,
+            emoji_list,
+        }
+    }
+}
+
+impl_method!(SetStickerEmojiList, bool, "setStickerEmojiList");
+
+/// Use this method to change search keywords assigned to a regular or custom emoji sticker. The sticker must belong to a sticker set created by the bot. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SetStickerKeywords {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A JSON-serialized list of 0-20 search keywords for the sticker with total length of up to 64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+}
+impl SetStickerKeywords {
+    pub fn new(sticker: String) -> Self {
+        Self {
+            sticker,
+            keywords: None,
+        }
+    }
+}
+
+impl_method!(SetStickerKeywords, bool, "setStickerKeywords");
+
+/// Use this method to change the mask position of a mask sticker. The sticker must belong to a sticker set that was created by the bot. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SetStickerMaskPosition {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A JSON-serialized object with the position where the mask should be placed on faces. Omit the parameter to remove the mask position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<types::MaskPosition>,
+}
+impl SetStickerMaskPosition {
+    pub fn new(sticker: String) -> Self {
+        Self {
+            sticker,
+            mask_position: None,
+        }
     }
 }
 
+impl_method!(SetStickerMaskPosition, bool, "setStickerMaskPosition");
+
 /// Use this method to set the thumbnail of a sticker set. Animated thumbnails can be set for animated sticker sets only. Video thumbnails can be set only for video sticker sets only. Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SetStickerSetThumb {
@@ -2564,7 +3514,7 @@ pub struct SetStickerSetThumb {
     /// User identifier of the sticker set owner
     pub user_id: i64,
     /// A PNG image with the thumbnail, must be up to 128 kilobytes in size and have width and height exactly 100px, or a TGS animation with the thumbnail up to 32 kilobytes in size; see https://core.telegram.org/stickers#animated-sticker-requirements for animated sticker technical requirements, or a WEBM video with the thumbnail up to 32 kilobytes in size; see https://core.telegram.org/stickers#video-sticker-requirements for video sticker technical requirements. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More information on Sending Files ». Animated sticker set thumbnails can't be uploaded via HTTP URL.
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "skip_file_param")]
     pub thumb: Option<types::InputFile>,
 }
 impl SetStickerSetThumb {
@@ -2577,19 +3527,12 @@ impl SetStickerSetThumb {
     }
 }
 
-impl Methods for SetStickerSetThumb {
-    fn endpoint(&self) -> String {
-        "setStickerSetThumb".to_string()
-    }
-
-    fn files(&self) -> HashMap<String, types::InputFile> {
-        let mut result = HashMap::new();
-        if let Some(thumb) = &self.thumb {
-            result.insert("thumb".to_string(), thumb.clone());
-        }
-        result
-    }
-}
+impl_method!(
+    SetStickerSetThumb,
+    bool,
+    "setStickerSetThumb",
+    optional_files { "thumb" => thumb }
+);
 
 /// Use this method to send answers to an inline query. On success, True is returned. No more than 50 results per query are allowed.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2628,11 +3571,7 @@ impl AnswerInlineQuery {
     }
 }
 
-impl Methods for AnswerInlineQuery {
-    fn endpoint(&self) -> String {
-        "answerInlineQuery".to_string()
-    }
-}
+impl_method!(AnswerInlineQuery, bool, "answerInlineQuery");
 
 /// Use this method to set the result of an interaction with a Web App and send a corresponding message on behalf of the user to the chat from which the query originated. On success, a SentWebAppMessage object is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2651,12 +3590,75 @@ impl AnswerWebAppQuery {
     }
 }
 
-impl Methods for AnswerWebAppQuery {
-    fn endpoint(&self) -> String {
-        "answerWebAppQuery".to_string()
+impl_method!(
+    AnswerWebAppQuery,
+    types::SentWebAppMessage,
+    "answerWebAppQuery"
+);
+
+/// Stores a message that can be sent by a user of a Mini App. Returns a PreparedInlineMessage object.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SavePreparedInlineMessage {
+    /// Unique identifier of the target user that can use the prepared message
+    pub user_id: i64,
+    /// A JSON-serialized object describing the message to be sent
+    pub result: types::InlineQueryResult,
+    /// Pass True if the message can be sent to private chats with users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_user_chats: Option<bool>,
+    /// Pass True if the message can be sent to private chats with bots
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_bot_chats: Option<bool>,
+    /// Pass True if the message can be sent to group and supergroup chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_group_chats: Option<bool>,
+    /// Pass True if the message can be sent to channel chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_channel_chats: Option<bool>,
+}
+impl SavePreparedInlineMessage {
+    pub fn new(user_id: i64, result: types::InlineQueryResult) -> Self {
+        Self {
+            user_id,
+            result,
+            allow_user_chats: None,
+            allow_bot_chats: None,
+            allow_group_chats: None,
+            allow_channel_chats: None,
+        }
+    }
+}
+
+impl_method!(
+    SavePreparedInlineMessage,
+    types::PreparedInlineMessage,
+    "savePreparedInlineMessage"
+);
+
+/// Changes the emoji status for a given user that previously allowed the bot to manage their emoji status via the Mini App method requestEmojiStatusAccess. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SetUserEmojiStatus {
+    /// Unique identifier of the target user
+    pub user_id: i64,
+    /// Custom emoji identifier of the emoji status to set. Pass an empty string to remove the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_status_custom_emoji_id: Option<String>,
+    /// Expiration date of the emoji status, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_status_expiration_date: Option<i64>,
+}
+impl SetUserEmojiStatus {
+    pub fn new(user_id: i64) -> Self {
+        Self {
+            user_id,
+            emoji_status_custom_emoji_id: None,
+            emoji_status_expiration_date: None,
+        }
     }
 }
 
+impl_method!(SetUserEmojiStatus, bool, "setUserEmojiStatus");
+
 /// Use this method to send invoices. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendInvoice {
@@ -2725,6 +3727,9 @@ pub struct SendInvoice {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -2770,6 +3775,7 @@ impl SendInvoice {
             is_flexible: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -2777,11 +3783,7 @@ impl SendInvoice {
     }
 }
 
-impl Methods for SendInvoice {
-    fn endpoint(&self) -> String {
-        "sendInvoice".to_string()
-    }
-}
+impl_method!(SendInvoice, types::Message, "sendInvoice");
 
 /// Use this method to create a link for an invoice. Returns the created invoice link as String on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2875,11 +3877,7 @@ impl CreateInvoiceLink {
     }
 }
 
-impl Methods for CreateInvoiceLink {
-    fn endpoint(&self) -> String {
-        "createInvoiceLink".to_string()
-    }
-}
+impl_method!(CreateInvoiceLink, String, "createInvoiceLink");
 
 /// If you sent an invoice requesting a shipping address and the parameter is_flexible was specified, the Bot API will send an Update with a shipping_query field to the bot. Use this method to reply to shipping queries. On success, True is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2906,11 +3904,7 @@ impl AnswerShippingQuery {
     }
 }
 
-impl Methods for AnswerShippingQuery {
-    fn endpoint(&self) -> String {
-        "answerShippingQuery".to_string()
-    }
-}
+impl_method!(AnswerShippingQuery, bool, "answerShippingQuery");
 
 /// Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation in the form of an Update with the field pre_checkout_query. Use this method to respond to such pre-checkout queries. On success, True is returned. Note: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -2933,12 +3927,74 @@ impl AnswerPreCheckoutQuery {
     }
 }
 
-impl Methods for AnswerPreCheckoutQuery {
-    fn endpoint(&self) -> String {
-        "answerPreCheckoutQuery".to_string()
+impl_method!(AnswerPreCheckoutQuery, bool, "answerPreCheckoutQuery");
+
+/// Refunds a successful payment in Telegram Stars. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RefundStarPayment {
+    /// Identifier of the user whose payment will be refunded
+    pub user_id: i64,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+}
+impl RefundStarPayment {
+    pub fn new(user_id: i64, telegram_payment_charge_id: String) -> Self {
+        Self {
+            user_id,
+            telegram_payment_charge_id,
+        }
+    }
+}
+
+impl_method!(RefundStarPayment, bool, "refundStarPayment");
+
+/// Allows the bot to cancel or re-enable extension of a subscription paid in Telegram Stars. Returns True on success.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EditUserStarSubscription {
+    /// Identifier of the user whose subscription will be edited
+    pub user_id: i64,
+    /// Telegram payment identifier for the subscription
+    pub telegram_payment_charge_id: String,
+    /// Pass True to cancel extension of the user subscription; the subscription must be active up to the end of the current subscription period. Pass False to allow the user to re-enable a subscription that was previously canceled by the bot.
+    pub is_canceled: bool,
+}
+impl EditUserStarSubscription {
+    pub fn new(user_id: i64, telegram_payment_charge_id: String, is_canceled: bool) -> Self {
+        Self {
+            user_id,
+            telegram_payment_charge_id,
+            is_canceled,
+        }
+    }
+}
+
+impl_method!(EditUserStarSubscription, bool, "editUserStarSubscription");
+
+/// Returns the bot's Telegram Star transactions in chronological order. On success, returns a StarTransactions object.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GetStarTransactions {
+    /// Number of transactions to skip in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// The maximum number of transactions to be retrieved. Values between 1-100 are accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+impl GetStarTransactions {
+    pub fn new() -> Self {
+        Self {
+            offset: None,
+            limit: None,
+        }
     }
 }
 
+impl_method!(
+    GetStarTransactions,
+    types::StarTransactions,
+    "getStarTransactions"
+);
+
 /// Informs a user that some of the Telegram Passport elements they provided contains errors. The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SetPassportDataErrors {
@@ -2953,16 +4009,12 @@ impl SetPassportDataErrors {
     }
 }
 
-impl Methods for SetPassportDataErrors {
-    fn endpoint(&self) -> String {
-        "setPassportDataErrors".to_string()
-    }
-}
+impl_method!(SetPassportDataErrors, bool, "setPassportDataErrors");
 
 /// Use this method to send a game. On success, the sent Message is returned.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SendGame {
-    /// Unique identifier for the target chat
+    /// Unique identifier for the target chat. Games can't be sent to channels, and the API only accepts a numeric identifier here.
     pub chat_id: i64,
     /// Short name of the game, serves as the unique identifier for the game. Set up your games via @BotFather.
     pub game_short_name: String,
@@ -2972,6 +4024,9 @@ pub struct SendGame {
     /// Protects the contents of the sent message from forwarding and saving
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -2989,6 +4044,7 @@ impl SendGame {
             game_short_name,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_to_message_id: None,
             allow_sending_without_reply: None,
             reply_markup: None,
@@ -2996,11 +4052,7 @@ impl SendGame {
     }
 }
 
-impl Methods for SendGame {
-    fn endpoint(&self) -> String {
-        "sendGame".to_string()
-    }
-}
+impl_method!(SendGame, types::Message, "sendGame");
 
 /// Use this method to set the score of the specified user in a game message. On success, if the message is not an inline message, the Message is returned, otherwise True is returned. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -3015,7 +4067,7 @@ pub struct SetGameScore {
     /// Pass True if the game message should not be automatically edited to include the current scoreboard
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_edit_message: Option<bool>,
-    /// Required if inline_message_id is not specified. Unique identifier for the target chat
+    /// Required if inline_message_id is not specified. Unique identifier for the target chat. Like sendGame, this only accepts a numeric identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
     /// Required if inline_message_id is not specified. Identifier of the sent message
@@ -3039,18 +4091,14 @@ impl SetGameScore {
     }
 }
 
-impl Methods for SetGameScore {
-    fn endpoint(&self) -> String {
-        "setGameScore".to_string()
-    }
-}
+impl_method!(SetGameScore, types::MayBeMessage, "setGameScore");
 
 /// Use this method to get data for high score tables. Will return the score of the specified user and several of their neighbors in a game. Returns an Array of GameHighScore objects.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GetGameHighScores {
     /// Target user id
     pub user_id: i64,
-    /// Required if inline_message_id is not specified. Unique identifier for the target chat
+    /// Required if inline_message_id is not specified. Unique identifier for the target chat. Like sendGame, this only accepts a numeric identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
     /// Required if inline_message_id is not specified. Identifier of the sent message
@@ -3071,8 +4119,8 @@ impl GetGameHighScores {
     }
 }
 
-impl Methods for GetGameHighScores {
-    fn endpoint(&self) -> String {
-        "getGameHighScores".to_string()
-    }
-}
+impl_method!(
+    GetGameHighScores,
+    Vec<types::GameHighScore>,
+    "getGameHighScores"
+);
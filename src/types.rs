@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 /// This object represents an incoming update.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Update {
     /// The update's unique identifier. Update identifiers start from a certain positive number and increase sequentially. This ID becomes especially handy if you're using webhooks, since it allows you to ignore repeated updates or to restore the correct update sequence, should they get out of order. If there are no new updates for at least a week, then identifier of the next update will be chosen randomly instead of sequentially.
     pub update_id: i64,
@@ -52,6 +53,12 @@ pub struct Update {
     /// Optional. A request to join the chat has been sent. The bot must have the can_invite_users administrator right in the chat to receive these updates.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_join_request: Option<ChatJoinRequest>,
+    /// Optional. A chat boost was added or changed. The bot must be an administrator in the chat to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_boost: Option<ChatBoostUpdated>,
+    /// Optional. A boost was removed from a chat. The bot must be an administrator in the chat to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_chat_boost: Option<ChatBoostRemoved>,
 }
 impl Update {
     pub fn new(update_id: i64) -> Self {
@@ -71,12 +78,110 @@ impl Update {
             my_chat_member: None,
             chat_member: None,
             chat_join_request: None,
+            chat_boost: None,
+            removed_chat_boost: None,
         }
     }
+    /// Sets `message` and returns the update.
+    pub fn with_message(mut self, message: Message) -> Self {
+        self.message = Some(message);
+        self
+    }
+    /// Sets `edited_message` and returns the update.
+    pub fn with_edited_message(mut self, edited_message: Message) -> Self {
+        self.edited_message = Some(edited_message);
+        self
+    }
+    /// Sets `channel_post` and returns the update.
+    pub fn with_channel_post(mut self, channel_post: Message) -> Self {
+        self.channel_post = Some(channel_post);
+        self
+    }
+    /// Sets `edited_channel_post` and returns the update.
+    pub fn with_edited_channel_post(mut self, edited_channel_post: Message) -> Self {
+        self.edited_channel_post = Some(edited_channel_post);
+        self
+    }
+    /// Sets `inline_query` and returns the update.
+    pub fn with_inline_query(mut self, inline_query: InlineQuery) -> Self {
+        self.inline_query = Some(inline_query);
+        self
+    }
+    /// Sets `chosen_inline_result` and returns the update.
+    pub fn with_chosen_inline_result(mut self, chosen_inline_result: ChosenInlineResult) -> Self {
+        self.chosen_inline_result = Some(chosen_inline_result);
+        self
+    }
+    /// Sets `callback_query` and returns the update.
+    pub fn with_callback_query(mut self, callback_query: CallbackQuery) -> Self {
+        self.callback_query = Some(callback_query);
+        self
+    }
+    /// Sets `shipping_query` and returns the update.
+    pub fn with_shipping_query(mut self, shipping_query: ShippingQuery) -> Self {
+        self.shipping_query = Some(shipping_query);
+        self
+    }
+    /// Sets `pre_checkout_query` and returns the update.
+    pub fn with_pre_checkout_query(mut self, pre_checkout_query: PreCheckoutQuery) -> Self {
+        self.pre_checkout_query = Some(pre_checkout_query);
+        self
+    }
+    /// Sets `poll` and returns the update.
+    pub fn with_poll(mut self, poll: Poll) -> Self {
+        self.poll = Some(poll);
+        self
+    }
+    /// Sets `poll_answer` and returns the update.
+    pub fn with_poll_answer(mut self, poll_answer: PollAnswer) -> Self {
+        self.poll_answer = Some(poll_answer);
+        self
+    }
+    /// Sets `my_chat_member` and returns the update.
+    pub fn with_my_chat_member(mut self, my_chat_member: ChatMemberUpdated) -> Self {
+        self.my_chat_member = Some(my_chat_member);
+        self
+    }
+    /// Sets `chat_member` and returns the update.
+    pub fn with_chat_member(mut self, chat_member: ChatMemberUpdated) -> Self {
+        self.chat_member = Some(chat_member);
+        self
+    }
+    /// Sets `chat_join_request` and returns the update.
+    pub fn with_chat_join_request(mut self, chat_join_request: ChatJoinRequest) -> Self {
+        self.chat_join_request = Some(chat_join_request);
+        self
+    }
+    /// Sets `chat_boost` and returns the update.
+    pub fn with_chat_boost(mut self, chat_boost: ChatBoostUpdated) -> Self {
+        self.chat_boost = Some(chat_boost);
+        self
+    }
+    /// Sets `removed_chat_boost` and returns the update.
+    pub fn with_removed_chat_boost(mut self, removed_chat_boost: ChatBoostRemoved) -> Self {
+        self.removed_chat_boost = Some(removed_chat_boost);
+        self
+    }
+    /// effective_message returns the message carried by the update, checking in order
+    /// message, edited_message, channel_post, edited_channel_post and finally the
+    /// message attached to callback_query.
+    pub fn effective_message(&self) -> Option<&Message> {
+        self.message
+            .as_ref()
+            .or(self.edited_message.as_ref())
+            .or(self.channel_post.as_ref())
+            .or(self.edited_channel_post.as_ref())
+            .or_else(|| {
+                self.callback_query
+                    .as_ref()
+                    .and_then(|query| query.message.as_ref())
+            })
+    }
 }
 
 /// Describes the current status of a webhook.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WebhookInfo {
     /// Webhook URL, may be empty if webhook is not set up
     pub url: String,
@@ -121,6 +226,7 @@ impl WebhookInfo {
 
 /// This object represents a Telegram user or bot.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct User {
     /// Unique identifier for this user or bot. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier.
     pub id: i64,
@@ -152,6 +258,12 @@ pub struct User {
     /// Optional. True, if the bot supports inline queries. Returned only in getMe.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supports_inline_queries: Option<bool>,
+    /// Optional. True, if the bot can be connected to a Telegram Business account to receive its messages. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_connect_to_business: Option<bool>,
+    /// Optional. True, if the bot has a main Web App. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_main_web_app: Option<bool>,
 }
 impl User {
     pub fn new(id: i64, is_bot: bool, first_name: String) -> Self {
@@ -167,6 +279,8 @@ impl User {
             can_join_groups: None,
             can_read_all_group_messages: None,
             supports_inline_queries: None,
+            can_connect_to_business: None,
+            has_main_web_app: None,
         }
     }
 }
@@ -185,7 +299,9 @@ pub enum ChatType {
 }
 
 /// This object represents a chat.
+/// The fields marked "Returned only in getChat" are kept for backward compatibility; getChat itself now returns ChatFullInfo.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Chat {
     /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
     pub id: i64,
@@ -286,51 +402,480 @@ impl Chat {
     }
 }
 
+/// This object contains full information about a chat. Returned by getChat.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatFullInfo {
+    /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+    pub id: i64,
+    /// Type of chat, can be either “private”, “group”, “supergroup” or “channel”
+    #[serde(rename = "type")]
+    type_name: ChatType,
+    /// Optional. Title, for supergroups, channels and group chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Username, for private chats, supergroups and channels if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Optional. First name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Optional. Last name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Optional. Chat photo.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<ChatPhoto>,
+    /// Optional. For private chats, the date of birth of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthdate: Option<Birthdate>,
+    /// Optional. For private chats with business accounts, the intro of the business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_intro: Option<BusinessIntro>,
+    /// Optional. For private chats with business accounts, the location of the business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_location: Option<BusinessLocation>,
+    /// Optional. For private chats with business accounts, the opening hours of the business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_opening_hours: Option<BusinessOpeningHours>,
+    /// Optional. For private chats, the personal channel of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_chat: Option<Box<Chat>>,
+    /// Optional. Bio of the other party in a private chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    /// Optional. True, if privacy settings of the other party in the private chat allows to use tg://user?id=<user_id> links only in chats with the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_private_forwards: Option<bool>,
+    /// Optional. True, if the privacy settings of the other party restrict sending voice and video note messages in the private chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_restricted_voice_and_video_messages: Option<bool>,
+    /// Optional. True, if users need to join the supergroup before they can send messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_to_send_messages: Option<bool>,
+    /// Optional. True, if all users directly joining the supergroup need to be approved by supergroup administrators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_by_request: Option<bool>,
+    /// Optional. Description, for groups, supergroups and channel chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Primary invite link, for groups, supergroups and channel chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<String>,
+    /// Optional. The most recent pinned message (by sending date).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_message: Option<Message>,
+    /// Optional. Default chat member permissions, for groups and supergroups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ChatPermissions>,
+    /// Optional. For supergroups, the minimum allowed delay between consecutive messages sent by each unpriviledged user; in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slow_mode_delay: Option<i64>,
+    /// Optional. The time after which all messages sent to the chat will be automatically deleted; in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_auto_delete_time: Option<i64>,
+    /// Optional. True, if messages from the chat can't be forwarded to other chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_protected_content: Option<bool>,
+    /// Optional. For supergroups, name of group sticker set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker_set_name: Option<String>,
+    /// Optional. True, if the bot can change the group sticker set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_set_sticker_set: Option<bool>,
+    /// Optional. Unique identifier for the linked chat, i.e. the discussion group identifier for a channel and vice versa; for supergroups and channel chats. This identifier may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it. But it is smaller than 52 bits, so a signed 64 bit integer or double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_chat_id: Option<i64>,
+    /// Optional. For supergroups, the location to which the supergroup is connected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<ChatLocation>,
+    /// Optional. List of available reactions allowed in the chat. If omitted, then all emoji reactions are allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_reactions: Option<Vec<ReactionType>>,
+    /// Optional. The maximum number of reactions that can be set on a message in the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_reaction_count: Option<i64>,
+    /// Optional. Identifier of the accent color for the chat name and backgrounds of the chat photo, reply header, and link preview.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent_color_id: Option<i64>,
+    /// Optional. Custom emoji identifier of the emoji chosen by the chat for the reply header and link preview background
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_custom_emoji_id: Option<String>,
+    /// Optional. Identifier of the accent color for the chat's profile background.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_accent_color_id: Option<i64>,
+    /// Optional. Custom emoji identifier of the emoji status of the chat or the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_status_custom_emoji_id: Option<String>,
+    /// Optional. Expiration date of the emoji status of the chat or the other party in a private chat, in Unix time, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_status_expiration_date: Option<i64>,
+    /// Optional. True, if paid media messages can be sent or forwarded to the channel chat. The field is available only for channel chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_paid_media: Option<bool>,
+}
+impl ChatFullInfo {
+    pub fn new(id: i64, type_name: ChatType) -> Self {
+        Self {
+            id,
+            type_name,
+            title: None,
+            username: None,
+            first_name: None,
+            last_name: None,
+            photo: None,
+            birthdate: None,
+            business_intro: None,
+            business_location: None,
+            business_opening_hours: None,
+            personal_chat: None,
+            bio: None,
+            has_private_forwards: None,
+            has_restricted_voice_and_video_messages: None,
+            join_to_send_messages: None,
+            join_by_request: None,
+            description: None,
+            invite_link: None,
+            pinned_message: None,
+            permissions: None,
+            slow_mode_delay: None,
+            message_auto_delete_time: None,
+            has_protected_content: None,
+            sticker_set_name: None,
+            can_set_sticker_set: None,
+            linked_chat_id: None,
+            location: None,
+            available_reactions: None,
+            max_reaction_count: None,
+            accent_color_id: None,
+            background_custom_emoji_id: None,
+            profile_accent_color_id: None,
+            emoji_status_custom_emoji_id: None,
+            emoji_status_expiration_date: None,
+            can_send_paid_media: None,
+        }
+    }
+}
+
+/// Describes the birthdate of a user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Birthdate {
+    /// Day of the user's birth; 1-31
+    pub day: i64,
+    /// Month of the user's birth; 1-12
+    pub month: i64,
+    /// Optional. Year of the user's birth
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i64>,
+}
+impl Birthdate {
+    pub fn new(day: i64, month: i64) -> Self {
+        Self {
+            day,
+            month,
+            year: None,
+        }
+    }
+}
+
+/// Contains information about the start page settings of a Telegram Business account.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessIntro {
+    /// Optional. Title text of the business intro
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Message text of the business intro
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Optional. Sticker of the business intro
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker: Option<Sticker>,
+}
+impl BusinessIntro {
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            message: None,
+            sticker: None,
+        }
+    }
+}
+
+/// Contains information about the location of a Telegram Business account.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessLocation {
+    /// Address of the business
+    pub address: String,
+    /// Optional. Location of the business
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+impl BusinessLocation {
+    pub fn new(address: String) -> Self {
+        Self {
+            address,
+            location: None,
+        }
+    }
+}
+
+/// Describes an interval of time during which a business is open.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessOpeningHoursInterval {
+    /// The minute's sequence number in a week, starting on Monday, marking the start of the time interval during which the business is open; 0 - 7 * 24 * 60
+    pub opening_minute: i64,
+    /// The minute's sequence number in a week, starting on Monday, marking the end of the time interval during which the business is open; 0 - 8 * 24 * 60
+    pub closing_minute: i64,
+}
+impl BusinessOpeningHoursInterval {
+    pub fn new(opening_minute: i64, closing_minute: i64) -> Self {
+        Self {
+            opening_minute,
+            closing_minute,
+        }
+    }
+}
+
+/// Describes the opening hours of a business.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BusinessOpeningHours {
+    /// Unique name of the time zone for which the opening hours are defined
+    pub time_zone_name: String,
+    /// List of time intervals describing business opening hours
+    pub opening_hours: Vec<BusinessOpeningHoursInterval>,
+}
+impl BusinessOpeningHours {
+    pub fn new(time_zone_name: String, opening_hours: Vec<BusinessOpeningHoursInterval>) -> Self {
+        Self {
+            time_zone_name,
+            opening_hours,
+        }
+    }
+}
+
+/// The reaction is based on an emoji.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionTypeEmoji {
+    /// Reaction emoji.
+    pub emoji: String,
+}
+impl ReactionTypeEmoji {
+    pub fn new(emoji: String) -> Self {
+        Self { emoji }
+    }
+}
+
+/// The reaction is based on a custom emoji.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionTypeCustomEmoji {
+    /// Custom emoji identifier
+    pub custom_emoji_id: String,
+}
+impl ReactionTypeCustomEmoji {
+    pub fn new(custom_emoji_id: String) -> Self {
+        Self { custom_emoji_id }
+    }
+}
+
+/// The reaction is paid.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReactionTypePaid {}
+impl ReactionTypePaid {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// This object describes the type of a reaction. Currently, it can be one of
+/// ```text
+/// ReactionTypeEmoji
+/// ReactionTypeCustomEmoji
+/// ReactionTypePaid
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum ReactionType {
+    #[serde(rename = "emoji")]
+    ReactionTypeEmoji(ReactionTypeEmoji),
+    #[serde(rename = "custom_emoji")]
+    ReactionTypeCustomEmoji(ReactionTypeCustomEmoji),
+    #[serde(rename = "paid")]
+    ReactionTypePaid(ReactionTypePaid),
+}
+
+/// The emoji Telegram accepts for ReactionTypeEmoji reactions.
+pub const STANDARD_REACTION_EMOJI: &[&str] = &[
+    "👍",
+    "👎",
+    "❤",
+    "🔥",
+    "🥰",
+    "👏",
+    "😁",
+    "🤔",
+    "🤯",
+    "😱",
+    "🤬",
+    "😢",
+    "🎉",
+    "🤩",
+    "🤮",
+    "💩",
+    "🙏",
+    "👌",
+    "🕊",
+    "🤡",
+    "🥱",
+    "🥴",
+    "😍",
+    "🐳",
+    "❤‍🔥",
+    "🌚",
+    "🌭",
+    "💯",
+    "🤣",
+    "⚡",
+    "🍌",
+    "🏆",
+    "💔",
+    "🤨",
+    "😐",
+    "🍓",
+    "🍾",
+    "💋",
+    "🖕",
+    "😈",
+    "😴",
+    "😭",
+    "🤓",
+    "👻",
+    "👨‍💻",
+    "👀",
+    "🎃",
+    "🙈",
+    "😇",
+    "😨",
+    "🤝",
+    "✍",
+    "🤗",
+    "🫡",
+    "🎅",
+    "🎄",
+    "☃",
+    "💅",
+    "🤪",
+    "🗿",
+    "🆒",
+    "💘",
+    "🙉",
+    "🦄",
+    "😘",
+    "💊",
+    "🙊",
+    "😎",
+    "👾",
+    "🤷‍♂",
+    "🤷",
+    "🤷‍♀",
+    "😡",
+];
+
+impl ReactionType {
+    /// emoji creates a reaction with a standard emoji.
+    pub fn emoji(emoji: &str) -> Self {
+        Self::ReactionTypeEmoji(ReactionTypeEmoji::new(emoji.to_string()))
+    }
+    /// custom_emoji creates a reaction with a custom emoji.
+    pub fn custom_emoji(custom_emoji_id: String) -> Self {
+        Self::ReactionTypeCustomEmoji(ReactionTypeCustomEmoji::new(custom_emoji_id))
+    }
+    /// is_standard_emoji reports whether emoji is one of the emoji allowed as a reaction.
+    pub fn is_standard_emoji(emoji: &str) -> bool {
+        STANDARD_REACTION_EMOJI.contains(&emoji)
+    }
+}
+
 /// This object represents a message.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Message {
     /// Unique message identifier inside this chat
     pub message_id: i64,
+    /// Optional. Unique identifier of a message thread to which the message belongs; for supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Optional. Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub from: Option<User>,
     /// Optional. Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field from contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender_chat: Option<Box<Chat>>,
+    /// Optional. If the sender of the message boosted the chat, the number of its boosts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_boost_count: Option<i64>,
     /// Date the message was sent in Unix time
     pub date: i64,
     /// Conversation the message belongs to
     pub chat: Box<Chat>,
-    /// Optional. For forwarded messages, sender of the original message
+    /// Optional. Information about the original message for forwarded messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_origin: Option<MessageOrigin>,
+    /// Optional. For forwarded messages, sender of the original message. Deprecated in favor of `forward_origin`, kept for older Bot API servers.
+    #[deprecated(note = "use forward_origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from: Option<User>,
-    /// Optional. For messages forwarded from channels or from anonymous administrators, information about the original sender chat
+    /// Optional. For messages forwarded from channels or from anonymous administrators, information about the original sender chat. Deprecated in favor of `forward_origin`, kept for older Bot API servers.
+    #[deprecated(note = "use forward_origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from_chat: Option<Box<Chat>>,
-    /// Optional. For messages forwarded from channels, identifier of the original message in the channel
+    /// Optional. For messages forwarded from channels, identifier of the original message in the channel. Deprecated in favor of `forward_origin`, kept for older Bot API servers.
+    #[deprecated(note = "use forward_origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from_message_id: Option<i64>,
-    /// Optional. For forwarded messages that were originally sent in channels or by an anonymous chat administrator, signature of the message sender if present
+    /// Optional. For forwarded messages that were originally sent in channels or by an anonymous chat administrator, signature of the message sender if present. Deprecated in favor of `forward_origin`, kept for older Bot API servers.
+    #[deprecated(note = "use forward_origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_signature: Option<String>,
-    /// Optional. Sender's name for messages forwarded from users who disallow adding a link to their account in forwarded messages
+    /// Optional. Sender's name for messages forwarded from users who disallow adding a link to their account in forwarded messages. Deprecated in favor of `forward_origin`, kept for older Bot API servers.
+    #[deprecated(note = "use forward_origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_sender_name: Option<String>,
-    /// Optional. For forwarded messages, date the original message was sent in Unix time
+    /// Optional. For forwarded messages, date the original message was sent in Unix time. Deprecated in favor of `forward_origin`, kept for older Bot API servers.
+    #[deprecated(note = "use forward_origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_date: Option<i64>,
+    /// Optional. True, if the message is sent to a forum topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_topic_message: Option<bool>,
     /// Optional. True, if the message is a channel post that was automatically forwarded to the connected discussion group
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_automatic_forward: Option<bool>,
     /// Optional. For replies, the original message. Note that the Message object in this field will not contain further reply_to_message fields even if it itself is a reply.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message: Option<Box<Message>>,
+    /// Optional. Information about the message that is being replied to, which may come from another chat or forum topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_reply: Option<ExternalReplyInfo>,
+    /// Optional. For replies that quote part of the original message, the quoted part of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<TextQuote>,
     /// Optional. Bot through which the message was sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub via_bot: Option<User>,
     /// Optional. Date the message was last edited in Unix time
     #[serde(skip_serializing_if = "Option::is_none")]
     pub edit_date: Option<i64>,
+    /// Optional. True, if the message was sent by an implicit action, for example, as an away or a greeting business message, or as a scheduled message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_from_offline: Option<bool>,
     /// Optional. True, if the message can't be forwarded
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_protected_content: Option<bool>,
@@ -340,6 +885,9 @@ pub struct Message {
     /// Optional. Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author_signature: Option<String>,
+    /// Optional. Unique identifier of the message effect added to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_id: Option<String>,
     /// Optional. For text messages, the actual UTF-8 text of the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
@@ -361,9 +909,15 @@ pub struct Message {
     /// Optional. Message is a sticker, information about the sticker
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker: Option<Sticker>,
+    /// Optional. Message is a forwarded story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story: Option<Story>,
     /// Optional. Message is a video, information about the video
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<Video>,
+    /// Optional. Message contains paid media; information about the paid media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_media: Option<PaidMediaInfo>,
     /// Optional. Message is a video note, information about the video message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_note: Option<VideoNote>,
@@ -376,6 +930,9 @@ pub struct Message {
     /// Optional. For messages with a caption, special entities like usernames, URLs, bot commands, etc. that appear in the caption
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
     /// Optional. Message is a shared contact, information about the contact
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contact: Option<Contact>,
@@ -436,6 +993,12 @@ pub struct Message {
     /// Optional. Message is a service message about a successful payment, information about the payment. More about payments »
     #[serde(skip_serializing_if = "Option::is_none")]
     pub successful_payment: Option<SuccessfulPayment>,
+    /// Optional. Service message: users were shared with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users_shared: Option<UsersShared>,
+    /// Optional. Service message: a chat was shared with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_shared: Option<ChatShared>,
     /// Optional. The domain name of the website on which the user has logged in. More about Telegram Login »
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connected_website: Option<String>,
@@ -445,6 +1008,18 @@ pub struct Message {
     /// Optional. Service message. A user in the chat triggered another user's proximity alert while sharing Live Location.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proximity_alert_triggered: Option<ProximityAlertTriggered>,
+    /// Optional. Service message: a scheduled giveaway was created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_created: Option<GiveawayCreated>,
+    /// Optional. The message is a scheduled giveaway message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway: Option<Giveaway>,
+    /// Optional. A giveaway with public winners was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_winners: Option<GiveawayWinners>,
+    /// Optional. Service message: a giveaway without public winners was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_completed: Option<GiveawayCompleted>,
     /// Optional. Service message: video chat scheduled
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_chat_scheduled: Option<VideoChatScheduled>,
@@ -465,26 +1040,35 @@ pub struct Message {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 impl Message {
+    #[allow(deprecated)]
     pub fn new(message_id: i64, date: i64, chat: Box<Chat>) -> Self {
         Self {
             message_id,
+            message_thread_id: None,
             from: None,
             sender_chat: None,
+            sender_boost_count: None,
             date,
             chat,
+            forward_origin: None,
             forward_from: None,
             forward_from_chat: None,
             forward_from_message_id: None,
             forward_signature: None,
             forward_sender_name: None,
             forward_date: None,
+            is_topic_message: None,
             is_automatic_forward: None,
             reply_to_message: None,
+            external_reply: None,
+            quote: None,
             via_bot: None,
             edit_date: None,
+            is_from_offline: None,
             has_protected_content: None,
             media_group_id: None,
             author_signature: None,
+            effect_id: None,
             text: None,
             entities: None,
             animation: None,
@@ -492,11 +1076,14 @@ impl Message {
             document: None,
             photo: None,
             sticker: None,
+            story: None,
             video: None,
+            paid_media: None,
             video_note: None,
             voice: None,
             caption: None,
             caption_entities: None,
+            show_caption_above_media: None,
             contact: None,
             dice: None,
             game: None,
@@ -517,9 +1104,15 @@ impl Message {
             pinned_message: None,
             invoice: None,
             successful_payment: None,
+            users_shared: None,
+            chat_shared: None,
             connected_website: None,
             passport_data: None,
             proximity_alert_triggered: None,
+            giveaway_created: None,
+            giveaway: None,
+            giveaway_winners: None,
+            giveaway_completed: None,
             video_chat_scheduled: None,
             video_chat_started: None,
             video_chat_ended: None,
@@ -530,88 +1123,350 @@ impl Message {
     }
 }
 
-/// This object represents a unique message identifier.
+/// The message was originally sent by a known user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct MessageId {
-    /// Unique message identifier
-    pub message_id: i64,
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginUser {
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// User that sent the message originally
+    pub sender_user: User,
 }
-impl MessageId {
-    pub fn new(message_id: i64) -> Self {
-        Self { message_id }
+impl MessageOriginUser {
+    pub fn new(date: i64, sender_user: User) -> Self {
+        Self { date, sender_user }
     }
 }
 
-/// This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc.
+/// The message was originally sent by an unknown user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct MessageEntity {
-    /// Type of the entity. Currently, can be “mention” (@username), “hashtag” (#hashtag), “cashtag” ($USD), “bot_command” (/start@jobs_bot), “url” (https://telegram.org), “email” (do-not-reply@telegram.org), “phone_number” (+1-212-555-0123), “bold” (bold text), “italic” (italic text), “underline” (underlined text), “strikethrough” (strikethrough text), “spoiler” (spoiler message), “code” (monowidth string), “pre” (monowidth block), “text_link” (for clickable text URLs), “text_mention” (for users without usernames), “custom_emoji” (for inline custom emoji stickers)
-    #[serde(rename = "type")]
-    pub type_name: String,
-    /// Offset in UTF-16 code units to the start of the entity
-    pub offset: i64,
-    /// Length of the entity in UTF-16 code units
-    pub length: i64,
-    /// Optional. For “text_link” only, URL that will be opened after user taps on the text
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    /// Optional. For “text_mention” only, the mentioned user
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
-    /// Optional. For “pre” only, the programming language of the entity text
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
-    /// Optional. For “custom_emoji” only, unique identifier of the custom emoji. Use getCustomEmojiStickers to get full information about the sticker
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_emoji_id: Option<String>,
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginHiddenUser {
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// Name of the user that sent the message originally
+    pub sender_user_name: String,
 }
-impl MessageEntity {
-    pub fn new(type_name: String, offset: i64, length: i64) -> Self {
+impl MessageOriginHiddenUser {
+    pub fn new(date: i64, sender_user_name: String) -> Self {
         Self {
-            type_name,
-            offset,
-            length,
-            url: None,
-            user: None,
-            language: None,
-            custom_emoji_id: None,
+            date,
+            sender_user_name,
         }
     }
 }
 
-/// This object represents one size of a photo or a file / sticker thumbnail.
+/// The message was originally sent on behalf of a chat to a group chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct PhotoSize {
-    /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
-    /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
-    pub file_unique_id: String,
-    /// Photo width
-    pub width: i64,
-    /// Photo height
-    pub height: i64,
-    /// Optional. File size in bytes
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginChat {
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// Chat that sent the message originally
+    pub sender_chat: Box<Chat>,
+    /// Optional. For messages originally sent by an anonymous chat administrator, original message author signature
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_size: Option<i64>,
+    pub author_signature: Option<String>,
 }
-impl PhotoSize {
-    pub fn new(file_id: String, file_unique_id: String, width: i64, height: i64) -> Self {
+impl MessageOriginChat {
+    pub fn new(date: i64, sender_chat: Box<Chat>) -> Self {
         Self {
-            file_id,
-            file_unique_id,
-            width,
-            height,
-            file_size: None,
+            date,
+            sender_chat,
+            author_signature: None,
         }
     }
 }
 
-/// This object represents an animation file (GIF or H.264/MPEG-4 AVC video without sound).
+/// The message was originally sent to a channel chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct Animation {
-    /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
-    /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageOriginChannel {
+    /// Date the message was sent originally in Unix time
+    pub date: i64,
+    /// Channel chat to which the message was originally sent
+    pub chat: Box<Chat>,
+    /// Unique message identifier inside the chat
+    pub message_id: i64,
+    /// Optional. Signature of the original post author
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_signature: Option<String>,
+}
+impl MessageOriginChannel {
+    pub fn new(date: i64, chat: Box<Chat>, message_id: i64) -> Self {
+        Self {
+            date,
+            chat,
+            message_id,
+            author_signature: None,
+        }
+    }
+}
+
+/// This object contains information about a message that is being replied to, which may come from another chat or forum topic.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ExternalReplyInfo {
+    /// Origin of the message replied to by the given message
+    pub origin: MessageOrigin,
+    /// Optional. Chat the original message belongs to. Available only if the chat is a supergroup or a channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat: Option<Box<Chat>>,
+    /// Optional. Unique message identifier inside the original chat. Available only if the original chat is a supergroup or a channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    /// Optional. Options used for link preview generation for the original message, if it is a text message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Optional. Message is an animation, information about the animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Animation>,
+    /// Optional. Message is an audio file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Audio>,
+    /// Optional. Message is a general file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Document>,
+    /// Optional. Message is a photo, available sizes of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+    /// Optional. Message is a sticker, information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker: Option<Sticker>,
+    /// Optional. Message is a forwarded story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story: Option<Story>,
+    /// Optional. Message is a video, information about the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<Video>,
+    /// Optional. Message is a video note, information about the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_note: Option<VideoNote>,
+    /// Optional. Message is a voice message, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+    /// Optional. True, if the message media is covered by a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_media_spoiler: Option<bool>,
+    /// Optional. Message is a shared location, information about the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Optional. Message is a native poll, information about the poll
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
+    /// Optional. Message is a venue, information about the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<Venue>,
+}
+impl ExternalReplyInfo {
+    pub fn new(origin: MessageOrigin) -> Self {
+        Self {
+            origin,
+            chat: None,
+            message_id: None,
+            link_preview_options: None,
+            animation: None,
+            audio: None,
+            document: None,
+            photo: None,
+            sticker: None,
+            story: None,
+            video: None,
+            video_note: None,
+            voice: None,
+            has_media_spoiler: None,
+            location: None,
+            poll: None,
+            venue: None,
+        }
+    }
+
+    /// is_media reports whether the replied-to message carries a file: an animation, audio,
+    /// document, photo, sticker, story, video, video note or voice message.
+    pub fn is_media(&self) -> bool {
+        self.animation.is_some()
+            || self.audio.is_some()
+            || self.document.is_some()
+            || self.photo.is_some()
+            || self.sticker.is_some()
+            || self.story.is_some()
+            || self.video.is_some()
+            || self.video_note.is_some()
+            || self.voice.is_some()
+    }
+}
+
+/// Describes the options used for link preview generation.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LinkPreviewOptions {
+    /// Optional. True, if the link preview is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+    /// Optional. URL to use for the link preview. If empty, then the first URL found in the message text will be used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Optional. True, if the media in the link preview is supposed to be shrunk; ignored if the URL isn't explicitly specified or media size change isn't supported for the preview
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_small_media: Option<bool>,
+    /// Optional. True, if the media in the link preview is supposed to be enlarged; ignored if the URL isn't explicitly specified or media size change isn't supported for the preview
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_large_media: Option<bool>,
+    /// Optional. True, if the link preview must be shown above the message text; otherwise, the link preview will be shown below the message text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_above_text: Option<bool>,
+}
+impl LinkPreviewOptions {
+    pub fn new() -> Self {
+        Self {
+            is_disabled: None,
+            url: None,
+            prefer_small_media: None,
+            prefer_large_media: None,
+            show_above_text: None,
+        }
+    }
+}
+
+/// This object contains information about the quoted part of a message that is replied to by the given message.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TextQuote {
+    /// Text of the quoted part of a message that is replied to by the given message
+    pub text: String,
+    /// Optional. Special entities that appear in the quote. Currently, only bold, italic, underline, strikethrough, spoiler, and custom_emoji entities are kept in quotes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Approximate quote position in the original message in UTF-16 code units as specified by the sender
+    pub position: i64,
+    /// Optional. True, if the quote was chosen manually by the message sender. Otherwise, the quote was added automatically by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_manual: Option<bool>,
+}
+impl TextQuote {
+    pub fn new(text: String, position: i64) -> Self {
+        Self {
+            text,
+            entities: None,
+            position,
+            is_manual: None,
+        }
+    }
+}
+
+/// This object represents a unique message identifier.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageId {
+    /// Unique message identifier
+    pub message_id: i64,
+}
+impl MessageId {
+    pub fn new(message_id: i64) -> Self {
+        Self { message_id }
+    }
+}
+
+/// This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageEntity {
+    /// Type of the entity. Currently, can be “mention” (@username), “hashtag” (#hashtag), “cashtag” ($USD), “bot_command” (/start@jobs_bot), “url” (https://telegram.org), “email” (do-not-reply@telegram.org), “phone_number” (+1-212-555-0123), “bold” (bold text), “italic” (italic text), “underline” (underlined text), “strikethrough” (strikethrough text), “spoiler” (spoiler message), “code” (monowidth string), “pre” (monowidth block), “text_link” (for clickable text URLs), “text_mention” (for users without usernames), “custom_emoji” (for inline custom emoji stickers)
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// Offset in UTF-16 code units to the start of the entity
+    pub offset: i64,
+    /// Length of the entity in UTF-16 code units
+    pub length: i64,
+    /// Optional. For “text_link” only, URL that will be opened after user taps on the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Optional. For “text_mention” only, the mentioned user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Optional. For “pre” only, the programming language of the entity text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Optional. For “custom_emoji” only, unique identifier of the custom emoji. Use getCustomEmojiStickers to get full information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+}
+impl MessageEntity {
+    pub fn new(type_name: String, offset: i64, length: i64) -> Self {
+        Self {
+            type_name,
+            offset,
+            length,
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+        }
+    }
+}
+
+/// deserialize_file_size accepts file_size as either an integer or a numeric string,
+/// as some local Bot API server deployments send it quoted.
+fn deserialize_file_size<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum IntOrString {
+        Int(i64),
+        String(String),
+    }
+    match Option::<IntOrString>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(IntOrString::Int(size)) => Ok(Some(size)),
+        Some(IntOrString::String(size)) => size
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+/// This object represents one size of a photo or a file / sticker thumbnail.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PhotoSize {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Photo width
+    pub width: i64,
+    /// Photo height
+    pub height: i64,
+    /// Optional. File size in bytes
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub file_size: Option<i64>,
+}
+impl PhotoSize {
+    pub fn new(file_id: String, file_unique_id: String, width: i64, height: i64) -> Self {
+        Self {
+            file_id,
+            file_unique_id,
+            width,
+            height,
+            file_size: None,
+        }
+    }
+}
+
+/// This object represents an animation file (GIF or H.264/MPEG-4 AVC video without sound).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Animation {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Video width as defined by sender
     pub width: i64,
@@ -620,7 +1475,10 @@ pub struct Animation {
     /// Duration of the video in seconds as defined by sender
     pub duration: i64,
     /// Optional. Animation thumbnail as defined by sender
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
     /// Optional. Original animation filename as defined by sender
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -629,7 +1487,11 @@ pub struct Animation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
     /// Optional. File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
 }
 impl Animation {
@@ -646,16 +1508,22 @@ impl Animation {
             width,
             height,
             duration,
+            thumbnail: None,
             thumb: None,
             file_name: None,
             mime_type: None,
             file_size: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
 }
 
 /// This object represents an audio file to be treated as music by the Telegram clients.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Audio {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
@@ -676,10 +1544,17 @@ pub struct Audio {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
     /// Optional. File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
     /// Optional. Thumbnail of the album cover to which the music file belongs
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 }
 impl Audio {
@@ -693,20 +1568,29 @@ impl Audio {
             file_name: None,
             mime_type: None,
             file_size: None,
+            thumbnail: None,
             thumb: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
 }
 
 /// This object represents a general file (as opposed to photos, voice messages and audio files).
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Document {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Optional. Document thumbnail as defined by sender
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
     /// Optional. Original filename as defined by sender
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -715,7 +1599,11 @@ pub struct Document {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
     /// Optional. File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
 }
 impl Document {
@@ -723,16 +1611,37 @@ impl Document {
         Self {
             file_id,
             file_unique_id,
+            thumbnail: None,
             thumb: None,
             file_name: None,
             mime_type: None,
             file_size: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
+}
+
+/// This object represents a story.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Story {
+    /// Chat that posted the story
+    pub chat: Box<Chat>,
+    /// Unique identifier for the story in the chat
+    pub id: i64,
+}
+impl Story {
+    pub fn new(chat: Box<Chat>, id: i64) -> Self {
+        Self { chat, id }
+    }
 }
 
 /// This object represents a video file.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Video {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
@@ -745,7 +1654,10 @@ pub struct Video {
     /// Duration of the video in seconds as defined by sender
     pub duration: i64,
     /// Optional. Video thumbnail
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
     /// Optional. Original filename as defined by sender
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -754,7 +1666,11 @@ pub struct Video {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
     /// Optional. File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
 }
 impl Video {
@@ -771,16 +1687,22 @@ impl Video {
             width,
             height,
             duration,
+            thumbnail: None,
             thumb: None,
             file_name: None,
             mime_type: None,
             file_size: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
 }
 
 /// This object represents a video message (available in Telegram apps as of v.4.0).
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VideoNote {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
@@ -791,10 +1713,17 @@ pub struct VideoNote {
     /// Duration of the video in seconds as defined by sender
     pub duration: i64,
     /// Optional. Video thumbnail
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
     /// Optional. File size in bytes
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
 }
 impl VideoNote {
@@ -804,14 +1733,20 @@ impl VideoNote {
             file_unique_id,
             length,
             duration,
+            thumbnail: None,
             thumb: None,
             file_size: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
 }
 
 /// This object represents a voice note.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Voice {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
@@ -823,7 +1758,11 @@ pub struct Voice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
     /// Optional. File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
 }
 impl Voice {
@@ -838,8 +1777,163 @@ impl Voice {
     }
 }
 
+/// HasDuration is implemented by the media types that carry a duration in seconds.
+pub trait HasDuration {
+    /// duration_secs returns the duration of the media in seconds.
+    fn duration_secs(&self) -> i64;
+    /// duration_formatted returns the duration as m:ss, or h:mm:ss from one hour on.
+    fn duration_formatted(&self) -> String {
+        let secs = self.duration_secs().max(0);
+        let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        if hours > 0 {
+            format!("{}:{:02}:{:02}", hours, minutes, seconds)
+        } else {
+            format!("{}:{:02}", minutes, seconds)
+        }
+    }
+}
+
+macro_rules! impl_has_duration {
+    ($($t:ty),*) => {
+        $(
+            impl HasDuration for $t {
+                fn duration_secs(&self) -> i64 {
+                    self.duration
+                }
+            }
+        )*
+    };
+}
+
+impl_has_duration!(Animation, Audio, Video, VideoNote, Voice);
+
+/// HasFileSize is implemented by the types that report the size of a file.
+pub trait HasFileSize {
+    /// file_size returns the file size in bytes, if known.
+    fn file_size(&self) -> Option<i64>;
+    /// file_size_mb returns the file size in mebibytes (1024 * 1024 bytes), if known.
+    fn file_size_mb(&self) -> Option<f64> {
+        self.file_size().map(|size| size as f64 / (1024.0 * 1024.0))
+    }
+}
+
+macro_rules! impl_has_file_size {
+    ($($t:ty),*) => {
+        $(
+            impl HasFileSize for $t {
+                fn file_size(&self) -> Option<i64> {
+                    self.file_size
+                }
+            }
+        )*
+    };
+}
+
+impl_has_file_size!(PhotoSize, Audio, Document, Video, Voice, Animation, Sticker, File);
+
+macro_rules! impl_input_thumbnail {
+    ($($t:ty),*) => {
+        $(
+            impl $t {
+                /// thumbnail returns the thumbnail, preferring `thumbnail` over the legacy `thumb` key.
+                pub fn thumbnail(&self) -> Option<&InputFile> {
+                    self.thumb.as_ref().or(self.legacy_thumb.as_ref())
+                }
+            }
+        )*
+    };
+}
+
+impl_input_thumbnail!(
+    InputMediaVideo,
+    InputMediaAnimation,
+    InputMediaAudio,
+    InputMediaDocument,
+    InputPaidMediaVideo
+);
+
+/// TelegramFile is implemented by the types that reference a file stored on the Telegram servers.
+pub trait TelegramFile {
+    /// file_id returns the identifier which can be used to download or reuse the file.
+    fn file_id(&self) -> &str;
+    /// file_unique_id returns the identifier which is supposed to be the same over time and for different bots.
+    fn file_unique_id(&self) -> &str;
+    /// to_get_file creates a GetFile request for this file.
+    fn to_get_file(&self) -> crate::methods::GetFile {
+        crate::methods::GetFile::new(self.file_id().to_string())
+    }
+}
+
+macro_rules! impl_telegram_file {
+    ($($t:ty),*) => {
+        $(
+            impl TelegramFile for $t {
+                fn file_id(&self) -> &str {
+                    &self.file_id
+                }
+                fn file_unique_id(&self) -> &str {
+                    &self.file_unique_id
+                }
+            }
+        )*
+    };
+}
+
+impl_telegram_file!(PhotoSize, Animation, Audio, Document, Video, VideoNote, Voice, Sticker, File);
+
+impl PhotoSize {
+    /// to_input_media creates an InputMediaPhoto that resends this photo by file_id.
+    pub fn to_input_media(&self) -> InputMediaPhoto {
+        InputMediaPhoto::new(InputFile::FileID(self.file_id.clone()))
+    }
+}
+
+impl Video {
+    /// to_input_media creates an InputMediaVideo that resends this video by file_id, keeping its dimensions and duration.
+    pub fn to_input_media(&self) -> InputMediaVideo {
+        InputMediaVideo {
+            width: Some(self.width),
+            height: Some(self.height),
+            duration: Some(self.duration),
+            ..InputMediaVideo::new(InputFile::FileID(self.file_id.clone()))
+        }
+    }
+}
+
+impl Audio {
+    /// to_input_media creates an InputMediaAudio that resends this audio by file_id, keeping its duration, performer and title.
+    pub fn to_input_media(&self) -> InputMediaAudio {
+        InputMediaAudio {
+            duration: Some(self.duration),
+            performer: self.performer.clone(),
+            title: self.title.clone(),
+            ..InputMediaAudio::new(InputFile::FileID(self.file_id.clone()))
+        }
+    }
+}
+
+impl Document {
+    /// to_input_media creates an InputMediaDocument that resends this document by file_id.
+    pub fn to_input_media(&self) -> InputMediaDocument {
+        InputMediaDocument::new(InputFile::FileID(self.file_id.clone()))
+    }
+}
+
+impl Animation {
+    /// to_input_media creates an InputMediaAnimation that resends this animation by file_id, keeping its dimensions and duration.
+    pub fn to_input_media(&self) -> InputMediaAnimation {
+        InputMediaAnimation {
+            width: Some(self.width),
+            height: Some(self.height),
+            duration: Some(self.duration),
+            ..InputMediaAnimation::new(InputFile::FileID(self.file_id.clone()))
+        }
+    }
+}
+
 /// This object represents a phone contact.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Contact {
     /// Contact's phone number
     pub phone_number: String,
@@ -869,6 +1963,7 @@ impl Contact {
 
 /// This object represents an animated emoji that displays a random value.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Dice {
     /// Emoji on which the dice throw animation is based
     pub emoji: String,
@@ -879,10 +1974,28 @@ impl Dice {
     pub fn new(emoji: String, value: i64) -> Self {
         Self { emoji, value }
     }
+    /// max_value returns the highest value the dice emoji can show, or None for an unknown emoji.
+    pub fn max_value(&self) -> Option<i64> {
+        match self.emoji.as_str() {
+            "🎲" | "🎯" | "🎳" => Some(6),
+            "🏀" | "⚽" => Some(5),
+            "🎰" => Some(64),
+            _ => None,
+        }
+    }
+    /// is_max reports whether the dice landed on the highest value for its emoji, e.g. a bullseye for “🎯”.
+    pub fn is_max(&self) -> bool {
+        self.max_value() == Some(self.value)
+    }
+    /// is_jackpot reports whether a “🎰” slot machine hit three sevens.
+    pub fn is_jackpot(&self) -> bool {
+        self.emoji == "🎰" && self.value == 64
+    }
 }
 
 /// This object contains information about one answer option in a poll.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PollOption {
     /// Option text, 1-100 characters
     pub text: String,
@@ -897,10 +2010,14 @@ impl PollOption {
 
 /// This object represents an answer of a user in a non-anonymous poll.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PollAnswer {
     /// Unique poll identifier
     pub poll_id: String,
-    /// The user, who changed the answer to the poll
+    /// Optional. The chat that changed the answer to the poll, if the voter is anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voter_chat: Option<Box<Chat>>,
+    /// The user, who changed the answer to the poll. For anonymous votes this is a placeholder user standing in for voter_chat, not a real user.
     pub user: User,
     /// 0-based identifiers of answer options, chosen by the user. May be empty if the user retracted their vote.
     pub option_ids: Vec<i64>,
@@ -909,14 +2026,26 @@ impl PollAnswer {
     pub fn new(poll_id: String, user: User, option_ids: Vec<i64>) -> Self {
         Self {
             poll_id,
+            voter_chat: None,
             user,
             option_ids,
         }
     }
+
+    /// voter_chat returns the chat that voted, if the vote is anonymous.
+    pub fn voter_chat(&self) -> Option<&Chat> {
+        self.voter_chat.as_deref()
+    }
+
+    /// is_anonymous_vote reports whether the answer was given on behalf of a chat, in which case user must not be treated as a real user.
+    pub fn is_anonymous_vote(&self) -> bool {
+        self.voter_chat.is_some()
+    }
 }
 
 /// This object contains information about a poll.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Poll {
     /// Unique poll identifier
     pub id: String,
@@ -982,6 +2111,7 @@ impl Poll {
 
 /// This object represents a point on the map.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Location {
     /// Longitude as defined by sender
     pub longitude: f64,
@@ -1011,10 +2141,26 @@ impl Location {
             proximity_alert_radius: None,
         }
     }
+
+    /// The mean Earth radius in meters used by distance_to.
+    pub const EARTH_RADIUS: f64 = 6_371_000.0;
+
+    /// distance_to returns the great-circle distance to other in meters, using the haversine
+    /// formula, comparable with ProximityAlertTriggered.distance.
+    pub fn distance_to(&self, other: &Location) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let delta_lat = (other.latitude - self.latitude).to_radians();
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        2.0 * Self::EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
 }
 
 /// This object represents a venue.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Venue {
     /// Venue location. Can't be a live location
     pub location: Location,
@@ -1051,6 +2197,7 @@ impl Venue {
 
 /// Describes data sent from a Web App to the bot.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WebAppData {
     /// The data. Be aware that a bad client can send arbitrary data in this field.
     pub data: String,
@@ -1061,10 +2208,17 @@ impl WebAppData {
     pub fn new(data: String, button_text: String) -> Self {
         Self { data, button_text }
     }
+    /// parse_json deserializes data, which Web Apps usually send as JSON. A bad client can send
+    /// arbitrary data, so a successful parse doesn't make the values trustworthy; validate them
+    /// as user input.
+    pub fn parse_json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.data)
+    }
 }
 
 /// This object represents the content of a service message, sent whenever a user in the chat triggers a proximity alert set by another user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ProximityAlertTriggered {
     /// User that triggered the alert
     pub traveler: User,
@@ -1085,6 +2239,7 @@ impl ProximityAlertTriggered {
 
 /// This object represents a service message about a change in auto-delete timer settings.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MessageAutoDeleteTimerChanged {
     /// New auto-delete time for messages in the chat; in seconds
     pub message_auto_delete_time: i64,
@@ -1099,6 +2254,7 @@ impl MessageAutoDeleteTimerChanged {
 
 /// This object represents a service message about a video chat scheduled in the chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VideoChatScheduled {
     /// Point in time (Unix timestamp) when the video chat is supposed to be started by a chat administrator
     pub start_date: i64,
@@ -1111,6 +2267,7 @@ impl VideoChatScheduled {
 
 /// This object represents a service message about a video chat started in the chat. Currently holds no information.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VideoChatStarted {}
 impl VideoChatStarted {
     pub fn new() -> Self {
@@ -1120,6 +2277,7 @@ impl VideoChatStarted {
 
 /// This object represents a service message about a video chat ended in the chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VideoChatEnded {
     /// Video chat duration in seconds
     pub duration: i64,
@@ -1132,6 +2290,7 @@ impl VideoChatEnded {
 
 /// This object represents a service message about new members invited to a video chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VideoChatParticipantsInvited {
     /// New members that were invited to the video chat
     pub users: Vec<User>,
@@ -1142,8 +2301,140 @@ impl VideoChatParticipantsInvited {
     }
 }
 
+/// This object represents a service message about the creation of a scheduled giveaway. Currently holds no information.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GiveawayCreated {}
+impl GiveawayCreated {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// This object represents a message about a scheduled giveaway.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Giveaway {
+    /// The list of chats which the user must join to participate in the giveaway
+    pub chats: Vec<Chat>,
+    /// Point in time (Unix timestamp) when winners of the giveaway will be selected
+    pub winners_selection_date: i64,
+    /// The number of users which are supposed to be selected as winners of the giveaway
+    pub winner_count: i64,
+    /// Optional. True, if only users who join the chats after the giveaway started should be eligible to win
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_new_members: Option<bool>,
+    /// Optional. True, if the list of giveaway winners will be visible to everyone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_public_winners: Option<bool>,
+    /// Optional. Description of additional giveaway prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_description: Option<String>,
+    /// Optional. A list of two-letter ISO 3166-1 alpha-2 country codes indicating the countries from which eligible users for the giveaway must come. If empty, then all users can participate in the giveaway. Users with a phone number that was bought on Fragment can always participate in giveaways.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_codes: Option<Vec<String>>,
+    /// Optional. The number of months the Telegram Premium subscription won from the giveaway will be active for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_subscription_month_count: Option<i64>,
+}
+impl Giveaway {
+    pub fn new(chats: Vec<Chat>, winners_selection_date: i64, winner_count: i64) -> Self {
+        Self {
+            chats,
+            winners_selection_date,
+            winner_count,
+            only_new_members: None,
+            has_public_winners: None,
+            prize_description: None,
+            country_codes: None,
+            premium_subscription_month_count: None,
+        }
+    }
+}
+
+/// This object represents a message about the completion of a giveaway with public winners.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GiveawayWinners {
+    /// The chat that created the giveaway
+    pub chat: Box<Chat>,
+    /// Identifier of the message with the giveaway in the chat
+    pub giveaway_message_id: i64,
+    /// Point in time (Unix timestamp) when winners of the giveaway were selected
+    pub winners_selection_date: i64,
+    /// Total number of winners in the giveaway
+    pub winner_count: i64,
+    /// List of up to 100 winners of the giveaway
+    pub winners: Vec<User>,
+    /// Optional. The number of other chats the user had to join in order to be eligible for the giveaway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_chat_count: Option<i64>,
+    /// Optional. The number of months the Telegram Premium subscription won from the giveaway will be active for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_subscription_month_count: Option<i64>,
+    /// Optional. Number of undistributed prizes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unclaimed_prize_count: Option<i64>,
+    /// Optional. True, if only users who had joined the chats after the giveaway started were eligible to win
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_new_members: Option<bool>,
+    /// Optional. True, if the giveaway was canceled because the payment for it was refunded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub was_refunded: Option<bool>,
+    /// Optional. Description of additional giveaway prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prize_description: Option<String>,
+}
+impl GiveawayWinners {
+    pub fn new(
+        chat: Box<Chat>,
+        giveaway_message_id: i64,
+        winners_selection_date: i64,
+        winner_count: i64,
+        winners: Vec<User>,
+    ) -> Self {
+        Self {
+            chat,
+            giveaway_message_id,
+            winners_selection_date,
+            winner_count,
+            winners,
+            additional_chat_count: None,
+            premium_subscription_month_count: None,
+            unclaimed_prize_count: None,
+            only_new_members: None,
+            was_refunded: None,
+            prize_description: None,
+        }
+    }
+}
+
+/// This object represents a service message about the completion of a giveaway without public winners.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GiveawayCompleted {
+    /// Number of winners in the giveaway
+    pub winner_count: i64,
+    /// Optional. Number of undistributed prizes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unclaimed_prize_count: Option<i64>,
+    /// Optional. Message with the giveaway that was completed, if it wasn't deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giveaway_message: Option<Box<Message>>,
+}
+impl GiveawayCompleted {
+    pub fn new(winner_count: i64) -> Self {
+        Self {
+            winner_count,
+            unclaimed_prize_count: None,
+            giveaway_message: None,
+        }
+    }
+}
+
 /// This object represent a user's profile pictures.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct UserProfilePhotos {
     /// Total number of profile pictures the target user has
     pub total_count: i64,
@@ -1161,13 +2452,18 @@ impl UserProfilePhotos {
 
 /// This object represents a file ready to be downloaded. The file can be downloaded via the link https://api.telegram.org/file/bot<token>/<file_path>. It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct File {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Optional. File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
     /// Optional. File path. Use https://api.telegram.org/file/bot<token>/<file_path> to get the file.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1182,10 +2478,47 @@ impl File {
             file_path: None,
         }
     }
+    /// download_url returns https://api.telegram.org/file/bot<token>/<file_path>, if the file_path is known.
+    pub fn download_url(&self, token: &str) -> Option<String> {
+        self.file_path
+            .as_ref()
+            .map(|file_path| format!("https://api.telegram.org/file/bot{}/{}", token, file_path))
+    }
+    /// download_stream_url returns the same URL as download_url, for callers that stream the response body.
+    pub fn download_stream_url(&self, token: &str) -> Option<String> {
+        self.download_url(token)
+    }
+    /// suggested_filename returns the basename of file_path, e.g. "file_1.jpg" for "photos/file_1.jpg".
+    pub fn suggested_filename(&self) -> Option<&str> {
+        self.file_path
+            .as_deref()
+            .and_then(|file_path| file_path.rsplit('/').next())
+            .filter(|name| !name.is_empty())
+    }
+}
+
+/// DownloadFile describes how to fetch a File returned by getFile. It is not a Telegram method:
+/// the file is downloaded with a plain GET request to url.
+#[derive(Debug, Clone)]
+pub struct DownloadFile {
+    /// URL to GET the file contents from
+    pub url: String,
+    /// Suggested name for the downloaded file, taken from the basename of file_path
+    pub filename: Option<String>,
+}
+impl DownloadFile {
+    /// new returns None if the File has no file_path.
+    pub fn new(file: &File, token: &str) -> Option<Self> {
+        Some(Self {
+            url: file.download_url(token)?,
+            filename: file.suggested_filename().map(|name| name.to_string()),
+        })
+    }
 }
 
 /// Describes a Web App.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WebAppInfo {
     /// An HTTPS URL of a Web App to be opened with additional data as specified in Initializing Web Apps
     pub url: String,
@@ -1198,6 +2531,7 @@ impl WebAppInfo {
 
 /// This object represents a custom keyboard with reply options (see Introduction to bots for details and examples).
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ReplyKeyboardMarkup {
     /// Array of button rows, each represented by an Array of KeyboardButton objects
     pub keyboard: Vec<Vec<KeyboardButton>>,
@@ -1224,13 +2558,38 @@ impl ReplyKeyboardMarkup {
             selective: None,
         }
     }
+    /// with_placeholder sets input_field_placeholder, which must be 1-64 characters.
+    pub fn with_placeholder(mut self, placeholder: String) -> Result<Self, ValidationError> {
+        validate_input_field_placeholder(&placeholder)?;
+        self.input_field_placeholder = Some(placeholder);
+        Ok(self)
+    }
 }
 
-/// This object represents one button of the reply keyboard. For simple text buttons String can be used instead of this object to specify text of the button. Optional fields web_app, request_contact, request_location, and request_poll are mutually exclusive.
+/// validate_input_field_placeholder checks the 1-64 character limit shared by ReplyKeyboardMarkup and ForceReply.
+fn validate_input_field_placeholder(placeholder: &str) -> Result<(), ValidationError> {
+    let length = placeholder.chars().count();
+    if !(1..=64).contains(&length) {
+        return Err(ValidationError::new(
+            "input_field_placeholder".to_string(),
+            format!("must be 1-64 characters, got {}", length),
+        ));
+    }
+    Ok(())
+}
+
+/// This object represents one button of the reply keyboard. For simple text buttons String can be used instead of this object to specify text of the button. Optional fields web_app, request_users, request_chat, request_contact, request_location, and request_poll are mutually exclusive.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct KeyboardButton {
     /// Text of the button. If none of the optional fields are used, it will be sent as a message when the button is pressed
     pub text: String,
+    /// Optional. If specified, pressing the button will open a list of suitable users. Identifiers of selected users will be sent to the bot in a “users_shared” service message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_users: Option<KeyboardButtonRequestUsers>,
+    /// Optional. If specified, pressing the button will open a list of suitable chats. Tapping on a chat will send its identifier to the bot in a “chat_shared” service message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_chat: Option<KeyboardButtonRequestChat>,
     /// Optional. If True, the user's phone number will be sent as a contact when the button is pressed. Available in private chats only.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_contact: Option<bool>,
@@ -1248,6 +2607,8 @@ impl KeyboardButton {
     pub fn new(text: String) -> Self {
         Self {
             text,
+            request_users: None,
+            request_chat: None,
             request_contact: None,
             request_location: None,
             request_poll: None,
@@ -1256,8 +2617,114 @@ impl KeyboardButton {
     }
 }
 
+/// This object defines the criteria used to request suitable users. The identifiers of the selected users will be shared with the bot when the corresponding button is pressed.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyboardButtonRequestUsers {
+    /// Signed 32-bit identifier of the request that will be received back in the UsersShared object. Must be unique within the message
+    pub request_id: i64,
+    /// Optional. Pass True to request bots, pass False to request regular users. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_bot: Option<bool>,
+    /// Optional. Pass True to request premium users, pass False to request non-premium users. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_premium: Option<bool>,
+    /// Optional. The maximum number of users to be selected; 1-10. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_quantity: Option<i64>,
+}
+impl KeyboardButtonRequestUsers {
+    pub fn new(request_id: i64) -> Self {
+        Self {
+            request_id,
+            user_is_bot: None,
+            user_is_premium: None,
+            max_quantity: None,
+        }
+    }
+}
+
+/// This object defines the criteria used to request a suitable chat. The identifier of the selected chat will be shared with the bot when the corresponding button is pressed.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyboardButtonRequestChat {
+    /// Signed 32-bit identifier of the request, which will be received back in the ChatShared object. Must be unique within the message
+    pub request_id: i64,
+    /// Pass True to request a channel chat, pass False to request a group or a supergroup chat.
+    pub chat_is_channel: bool,
+    /// Optional. Pass True to request a forum supergroup, pass False to request a non-forum chat. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_forum: Option<bool>,
+    /// Optional. Pass True to request a supergroup or a channel with a username, pass False to request a chat without a username. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_has_username: Option<bool>,
+    /// Optional. Pass True to request a chat owned by the user. Otherwise, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_created: Option<bool>,
+    /// Optional. A JSON-serialized object listing the required administrator rights of the user in the chat. The rights must be a superset of bot_administrator_rights. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_administrator_rights: Option<ChatAdministratorRights>,
+    /// Optional. A JSON-serialized object listing the required administrator rights of the bot in the chat. The rights must be a subset of user_administrator_rights. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_administrator_rights: Option<ChatAdministratorRights>,
+    /// Optional. Pass True to request a chat with the bot as a member. Otherwise, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_is_member: Option<bool>,
+}
+impl KeyboardButtonRequestChat {
+    pub fn new(request_id: i64, chat_is_channel: bool) -> Self {
+        Self {
+            request_id,
+            chat_is_channel,
+            chat_is_forum: None,
+            chat_has_username: None,
+            chat_is_created: None,
+            user_administrator_rights: None,
+            bot_administrator_rights: None,
+            bot_is_member: None,
+        }
+    }
+}
+
+/// This object contains information about the users whose identifiers were shared with the bot using a KeyboardButtonRequestUsers button.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UsersShared {
+    /// Identifier of the request
+    pub request_id: i64,
+    /// Identifiers of the shared users. These numbers may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting them. But they have at most 52 significant bits, so 64-bit integers or double-precision float types are safe for storing these identifiers. The bot may not have access to the users and could be unable to use these identifiers, unless the users are already known to the bot by some other means.
+    pub user_ids: Vec<i64>,
+}
+impl UsersShared {
+    pub fn new(request_id: i64, user_ids: Vec<i64>) -> Self {
+        Self {
+            request_id,
+            user_ids,
+        }
+    }
+}
+
+/// This object contains information about the chat whose identifier was shared with the bot using a KeyboardButtonRequestChat button.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatShared {
+    /// Identifier of the request
+    pub request_id: i64,
+    /// Identifier of the shared chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier. The bot may not have access to the chat and could be unable to use this identifier, unless the chat is already known to the bot by some other means.
+    pub chat_id: i64,
+}
+impl ChatShared {
+    pub fn new(request_id: i64, chat_id: i64) -> Self {
+        Self {
+            request_id,
+            chat_id,
+        }
+    }
+}
+
 /// This object represents type of a poll, which is allowed to be created and sent when the corresponding button is pressed.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct KeyboardButtonPollType {
     /// Optional. If quiz is passed, the user will be allowed to create only polls in the quiz mode. If regular is passed, only regular polls will be allowed. Otherwise, the user will be allowed to create a poll of any type.
     #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
@@ -1271,6 +2738,7 @@ impl KeyboardButtonPollType {
 
 /// Upon receiving a message with this object, Telegram clients will remove the current custom keyboard and display the default letter-keyboard. By default, custom keyboards are displayed until a new keyboard is sent by a bot. An exception is made for one-time keyboards that are hidden immediately after the user presses a button (see ReplyKeyboardMarkup).
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ReplyKeyboardRemove {
     /// Requests clients to remove the custom keyboard (user will not be able to summon this keyboard; if you want to hide the keyboard from sight but keep it accessible, use one_time_keyboard in ReplyKeyboardMarkup)
     pub remove_keyboard: bool,
@@ -1289,6 +2757,7 @@ impl ReplyKeyboardRemove {
 
 /// This object represents an inline keyboard that appears right next to the message it belongs to.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineKeyboardMarkup {
     /// Array of button rows, each represented by an Array of InlineKeyboardButton objects
     pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
@@ -1301,6 +2770,7 @@ impl InlineKeyboardMarkup {
 
 /// This object represents one button of an inline keyboard. You must use exactly one of the optional fields.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineKeyboardButton {
     /// Label text on the button
     pub text: String,
@@ -1347,6 +2817,7 @@ impl InlineKeyboardButton {
 
 /// This object represents a parameter of the inline keyboard button used to automatically authorize a user. Serves as a great replacement for the Telegram Login Widget when the user is coming from Telegram. All the user needs to do is tap/click a button and confirm that they want to log in:
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct LoginUrl {
     /// An HTTPS URL to be opened with user authorization data added to the query string when the button is pressed. If the user refuses to provide authorization data, the original URL without information about the user will be opened. The data added is the same as described in Receiving authorization data.
     pub url: String,
@@ -1373,6 +2844,7 @@ impl LoginUrl {
 
 /// This object represents an incoming callback query from a callback button in an inline keyboard. If the button that originated the query was attached to a message sent by the bot, the field message will be present. If the button was attached to a message sent via the bot (in inline mode), the field inline_message_id will be present. Exactly one of the fields data or game_short_name will be present.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CallbackQuery {
     /// Unique identifier for this query
     pub id: String,
@@ -1405,10 +2877,19 @@ impl CallbackQuery {
             game_short_name: None,
         }
     }
+    /// answer creates an AnswerCallbackQuery for this query that only stops the client's progress indicator.
+    pub fn answer(&self) -> crate::methods::AnswerCallbackQuery {
+        crate::methods::AnswerCallbackQuery::new(self.id.clone())
+    }
+    /// answer_text creates an AnswerCallbackQuery for this query that shows text as a notification.
+    pub fn answer_text(&self, text: String) -> crate::methods::AnswerCallbackQuery {
+        crate::methods::AnswerCallbackQuery::toast(self.id.clone(), text)
+    }
 }
 
 /// Upon receiving a message with this object, Telegram clients will display a reply interface to the user (act as if the user has selected the bot's message and tapped 'Reply'). This can be extremely useful if you want to create user-friendly step-by-step interfaces without having to sacrifice privacy mode.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ForceReply {
     /// Shows reply interface to the user, as if they manually selected the bot's message and tapped 'Reply'
     pub force_reply: bool,
@@ -1427,10 +2908,39 @@ impl ForceReply {
             selective: None,
         }
     }
+    /// with_placeholder sets input_field_placeholder, which must be 1-64 characters.
+    pub fn with_placeholder(mut self, placeholder: String) -> Result<Self, ValidationError> {
+        validate_input_field_placeholder(&placeholder)?;
+        self.input_field_placeholder = Some(placeholder);
+        Ok(self)
+    }
+}
+
+/// Selective is implemented by the reply markups that can target specific users only: the users
+/// mentioned in the message text and the sender of the message being replied to.
+pub trait Selective: Sized {
+    /// selective sets the selective field of the markup.
+    fn selective(self, selective: bool) -> Self;
+}
+
+macro_rules! impl_selective {
+    ($($t:ty),*) => {
+        $(
+            impl Selective for $t {
+                fn selective(mut self, selective: bool) -> Self {
+                    self.selective = Some(selective);
+                    self
+                }
+            }
+        )*
+    };
 }
 
+impl_selective!(ReplyKeyboardMarkup, ReplyKeyboardRemove, ForceReply);
+
 /// This object represents a chat photo.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatPhoto {
     /// File identifier of small (160x160) chat photo. This file_id can be used only for photo download and only for as long as the photo is not changed.
     pub small_file_id: String,
@@ -1459,6 +2969,7 @@ impl ChatPhoto {
 
 /// Represents an invite link for a chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatInviteLink {
     /// The invite link. If the link was created by another chat administrator, then the second part of the link will be replaced with “…”.
     pub invite_link: String,
@@ -1507,6 +3018,7 @@ impl ChatInviteLink {
 
 /// Represents the rights of an administrator in a chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatAdministratorRights {
     /// True, if the user's presence in the chat is hidden
     pub is_anonymous: bool,
@@ -1559,10 +3071,46 @@ impl ChatAdministratorRights {
             can_pin_messages: None,
         }
     }
+
+    /// full_for_group returns every right that applies to groups and supergroups.
+    pub fn full_for_group() -> Self {
+        Self {
+            can_pin_messages: Some(true),
+            ..Self::new(false, true, true, true, true, true, true, true)
+        }
+    }
+
+    /// full_for_channel returns every right that applies to channels.
+    pub fn full_for_channel() -> Self {
+        Self {
+            can_post_messages: Some(true),
+            can_edit_messages: Some(true),
+            ..Self::new(false, true, true, true, true, true, true, true)
+        }
+    }
+}
+
+impl From<ChatMemberAdministrator> for ChatAdministratorRights {
+    fn from(administrator: ChatMemberAdministrator) -> Self {
+        Self {
+            is_anonymous: administrator.is_anonymous,
+            can_manage_chat: administrator.can_manage_chat,
+            can_delete_messages: administrator.can_delete_messages,
+            can_manage_video_chats: administrator.can_manage_video_chats,
+            can_restrict_members: administrator.can_restrict_members,
+            can_promote_members: administrator.can_promote_members,
+            can_change_info: administrator.can_change_info,
+            can_invite_users: administrator.can_invite_users,
+            can_post_messages: administrator.can_post_messages,
+            can_edit_messages: administrator.can_edit_messages,
+            can_pin_messages: administrator.can_pin_messages,
+        }
+    }
 }
 
 /// Represents a chat member that owns the chat and has all administrator privileges.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberOwner {
     /// Information about the user
     pub user: User,
@@ -1584,6 +3132,7 @@ impl ChatMemberOwner {
 
 /// Represents a chat member that has some additional privileges.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberAdministrator {
     /// Information about the user
     pub user: User,
@@ -1652,6 +3201,7 @@ impl ChatMemberAdministrator {
 
 /// Represents a chat member that has no additional privileges or restrictions.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberMember {
     /// Information about the user
     pub user: User,
@@ -1664,6 +3214,7 @@ impl ChatMemberMember {
 
 /// Represents a chat member that is under certain restrictions in the chat. Supergroups only.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberRestricted {
     /// Information about the user
     pub user: User,
@@ -1677,7 +3228,26 @@ pub struct ChatMemberRestricted {
     pub can_pin_messages: bool,
     /// True, if the user is allowed to send text messages, contacts, locations and venues
     pub can_send_messages: bool,
-    /// True, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes
+    /// Optional. True, if the user is allowed to send audios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_audios: Option<bool>,
+    /// Optional. True, if the user is allowed to send documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_documents: Option<bool>,
+    /// Optional. True, if the user is allowed to send photos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_photos: Option<bool>,
+    /// Optional. True, if the user is allowed to send videos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_videos: Option<bool>,
+    /// Optional. True, if the user is allowed to send video notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_video_notes: Option<bool>,
+    /// Optional. True, if the user is allowed to send voice notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_voice_notes: Option<bool>,
+    /// True, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes. Newer servers send the granular can_send_* fields instead, in which case this is false.
+    #[serde(default)]
     pub can_send_media_messages: bool,
     /// True, if the user is allowed to send polls
     pub can_send_polls: bool,
@@ -1709,6 +3279,12 @@ impl ChatMemberRestricted {
             can_invite_users,
             can_pin_messages,
             can_send_messages,
+            can_send_audios: None,
+            can_send_documents: None,
+            can_send_photos: None,
+            can_send_videos: None,
+            can_send_video_notes: None,
+            can_send_voice_notes: None,
             can_send_media_messages,
             can_send_polls,
             can_send_other_messages,
@@ -1720,6 +3296,7 @@ impl ChatMemberRestricted {
 
 /// Represents a chat member that isn't currently a member of the chat, but may join it themselves.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberLeft {
     /// Information about the user
     pub user: User,
@@ -1732,6 +3309,7 @@ impl ChatMemberLeft {
 
 /// Represents a chat member that was banned in the chat and can't return to the chat or view chat messages.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberBanned {
     /// Information about the user
     pub user: User,
@@ -1746,6 +3324,7 @@ impl ChatMemberBanned {
 
 /// This object represents changes in the status of a chat member.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatMemberUpdated {
     /// Chat the user belongs to
     pub chat: Chat,
@@ -1763,58 +3342,241 @@ pub struct ChatMemberUpdated {
 }
 impl ChatMemberUpdated {
     pub fn new(
-        chat: Chat,
-        from: User,
-        date: i64,
-        old_chat_member: ChatMember,
-        new_chat_member: ChatMember,
+        chat: Chat,
+        from: User,
+        date: i64,
+        old_chat_member: ChatMember,
+        new_chat_member: ChatMember,
+    ) -> Self {
+        Self {
+            chat,
+            from,
+            date,
+            old_chat_member,
+            new_chat_member,
+            invite_link: None,
+        }
+    }
+
+    /// was_added reports whether the member joined or was added to the chat with this change.
+    /// For my_chat_member updates this means the bot itself was added.
+    pub fn was_added(&self) -> bool {
+        !self.old_chat_member.is_present() && self.new_chat_member.is_present()
+    }
+
+    /// was_removed reports whether the member left or was banned from the chat with this change.
+    /// For my_chat_member updates this means the bot itself was removed.
+    pub fn was_removed(&self) -> bool {
+        self.old_chat_member.is_present()
+            && matches!(self.new_chat_member.status(), "left" | "kicked")
+    }
+
+    /// affected_user returns the user whose membership changed.
+    pub fn affected_user(&self) -> &User {
+        self.new_chat_member.user()
+    }
+
+    /// summary describes the change in one line for audit logs, e.g.
+    /// "user 123 (Alice): member → administrator in chat -100 by 456".
+    pub fn summary(&self) -> String {
+        let user = self.affected_user();
+        format!(
+            "user {} ({}): {} → {} in chat {} by {}",
+            user.id,
+            user.first_name,
+            self.old_chat_member.status(),
+            self.new_chat_member.status(),
+            self.chat.id,
+            self.from.id
+        )
+    }
+}
+
+/// Represents a join request sent to a chat.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatJoinRequest {
+    /// Chat to which the request was sent
+    pub chat: Chat,
+    /// User that sent the join request
+    pub from: User,
+    /// Date the request was sent in Unix time
+    pub date: i64,
+    /// Optional. Bio of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    /// Optional. Chat invite link that was used by the user to send the join request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+}
+impl ChatJoinRequest {
+    pub fn new(chat: Chat, from: User, date: i64) -> Self {
+        Self {
+            chat,
+            from,
+            date,
+            bio: None,
+            invite_link: None,
+        }
+    }
+}
+
+/// The boost was obtained by subscribing to Telegram Premium or by gifting a Telegram Premium subscription to another user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostSourcePremium {
+    /// User that boosted the chat
+    pub user: User,
+}
+impl ChatBoostSourcePremium {
+    pub fn new(user: User) -> Self {
+        Self { user }
+    }
+}
+
+/// The boost was obtained by the creation of Telegram Premium gift codes to boost a chat.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostSourceGiftCode {
+    /// User for which the gift code was created
+    pub user: User,
+}
+impl ChatBoostSourceGiftCode {
+    pub fn new(user: User) -> Self {
+        Self { user }
+    }
+}
+
+/// The boost was obtained by the creation of a Telegram Premium giveaway.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostSourceGiveaway {
+    /// Identifier of a message in the chat with the giveaway; the message could have been deleted already. May be 0 if the message isn't sent yet.
+    pub giveaway_message_id: i64,
+    /// Optional. User that won the prize in the giveaway if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Optional. True, if the giveaway was completed, but there was no user to win the prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_unclaimed: Option<bool>,
+}
+impl ChatBoostSourceGiveaway {
+    pub fn new(giveaway_message_id: i64) -> Self {
+        Self {
+            giveaway_message_id,
+            user: None,
+            is_unclaimed: None,
+        }
+    }
+}
+
+/// This object contains information about a chat boost.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoost {
+    /// Unique identifier of the boost
+    pub boost_id: String,
+    /// Point in time (Unix timestamp) when the chat was boosted
+    pub add_date: i64,
+    /// Point in time (Unix timestamp) when the boost will automatically expire, unless the booster's Telegram Premium subscription is prolonged
+    pub expiration_date: i64,
+    /// Source of the added boost
+    pub source: ChatBoostSource,
+}
+impl ChatBoost {
+    pub fn new(
+        boost_id: String,
+        add_date: i64,
+        expiration_date: i64,
+        source: ChatBoostSource,
     ) -> Self {
         Self {
-            chat,
-            from,
-            date,
-            old_chat_member,
-            new_chat_member,
-            invite_link: None,
+            boost_id,
+            add_date,
+            expiration_date,
+            source,
         }
     }
 }
 
-/// Represents a join request sent to a chat.
+/// This object represents a boost added to a chat or changed.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct ChatJoinRequest {
-    /// Chat to which the request was sent
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostUpdated {
+    /// Chat which was boosted
     pub chat: Chat,
-    /// User that sent the join request
-    pub from: User,
-    /// Date the request was sent in Unix time
-    pub date: i64,
-    /// Optional. Bio of the user.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bio: Option<String>,
-    /// Optional. Chat invite link that was used by the user to send the join request
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invite_link: Option<ChatInviteLink>,
+    /// Information about the chat boost
+    pub boost: ChatBoost,
 }
-impl ChatJoinRequest {
-    pub fn new(chat: Chat, from: User, date: i64) -> Self {
+impl ChatBoostUpdated {
+    pub fn new(chat: Chat, boost: ChatBoost) -> Self {
+        Self { chat, boost }
+    }
+}
+
+/// This object represents a boost removed from a chat.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatBoostRemoved {
+    /// Chat which was boosted
+    pub chat: Chat,
+    /// Unique identifier of the boost
+    pub boost_id: String,
+    /// Point in time (Unix timestamp) when the boost was removed
+    pub remove_date: i64,
+    /// Source of the removed boost
+    pub source: ChatBoostSource,
+}
+impl ChatBoostRemoved {
+    pub fn new(chat: Chat, boost_id: String, remove_date: i64, source: ChatBoostSource) -> Self {
         Self {
             chat,
-            from,
-            date,
-            bio: None,
-            invite_link: None,
+            boost_id,
+            remove_date,
+            source,
         }
     }
 }
 
+/// This object represents a list of boosts added to a chat by a user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UserChatBoosts {
+    /// The list of boosts added to the chat by the user
+    pub boosts: Vec<ChatBoost>,
+}
+impl UserChatBoosts {
+    pub fn new(boosts: Vec<ChatBoost>) -> Self {
+        Self { boosts }
+    }
+}
+
 /// Describes actions that a non-administrator user is allowed to take in a chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatPermissions {
     /// Optional. True, if the user is allowed to send text messages, contacts, locations and venues
     #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_messages: Option<bool>,
-    /// Optional. True, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
+    /// Optional. True, if the user is allowed to send audios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_audios: Option<bool>,
+    /// Optional. True, if the user is allowed to send documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_documents: Option<bool>,
+    /// Optional. True, if the user is allowed to send photos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_photos: Option<bool>,
+    /// Optional. True, if the user is allowed to send videos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_videos: Option<bool>,
+    /// Optional. True, if the user is allowed to send video notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_video_notes: Option<bool>,
+    /// Optional. True, if the user is allowed to send voice notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_voice_notes: Option<bool>,
+    /// Optional. True, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages. Newer servers use the granular can_send_* fields instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_media_messages: Option<bool>,
     /// Optional. True, if the user is allowed to send polls, implies can_send_messages
@@ -1840,6 +3602,12 @@ impl ChatPermissions {
     pub fn new() -> Self {
         Self {
             can_send_messages: None,
+            can_send_audios: None,
+            can_send_documents: None,
+            can_send_photos: None,
+            can_send_videos: None,
+            can_send_video_notes: None,
+            can_send_voice_notes: None,
             can_send_media_messages: None,
             can_send_polls: None,
             can_send_other_messages: None,
@@ -1849,10 +3617,62 @@ impl ChatPermissions {
             can_pin_messages: None,
         }
     }
+
+    /// allow_all_media sets all the granular media permissions and returns the permissions.
+    pub fn allow_all_media(mut self) -> Self {
+        self.can_send_audios = Some(true);
+        self.can_send_documents = Some(true);
+        self.can_send_photos = Some(true);
+        self.can_send_videos = Some(true);
+        self.can_send_video_notes = Some(true);
+        self.can_send_voice_notes = Some(true);
+        self
+    }
+
+    /// all_granted returns permissions with every flag set to true.
+    pub fn all_granted() -> Self {
+        Self::with_all(true)
+    }
+
+    /// all_denied returns permissions with every flag set to false.
+    pub fn all_denied() -> Self {
+        Self::with_all(false)
+    }
+
+    /// read_only returns permissions that deny sending any kind of message. The other flags, like
+    /// can_change_info, are left unset so the chat's defaults apply.
+    pub fn read_only() -> Self {
+        Self {
+            can_change_info: None,
+            can_invite_users: None,
+            can_pin_messages: None,
+            ..Self::with_all(false)
+        }
+    }
+
+    fn with_all(value: bool) -> Self {
+        Self {
+            can_send_messages: Some(value),
+            can_send_audios: Some(value),
+            can_send_documents: Some(value),
+            can_send_photos: Some(value),
+            can_send_videos: Some(value),
+            can_send_video_notes: Some(value),
+            can_send_voice_notes: Some(value),
+            can_send_media_messages: Some(value),
+            can_send_polls: Some(value),
+            can_send_other_messages: Some(value),
+            can_add_web_page_previews: Some(value),
+            can_change_info: Some(value),
+            can_invite_users: Some(value),
+            can_pin_messages: Some(value),
+        }
+    }
 }
 
 /// Represents a location to which a chat is connected.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChatLocation {
     /// The location to which the supergroup is connected. Can't be a live location.
     pub location: Location,
@@ -1867,6 +3687,7 @@ impl ChatLocation {
 
 /// This object represents a bot command.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommand {
     /// Text of the command; 1-32 characters. Can contain only lowercase English letters, digits and underscores.
     pub command: String,
@@ -1884,6 +3705,7 @@ impl BotCommand {
 
 /// Represents the default scope of bot commands. Default commands are used if no commands with a narrower scope are specified for the user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeDefault {}
 impl BotCommandScopeDefault {
     pub fn new() -> Self {
@@ -1893,6 +3715,7 @@ impl BotCommandScopeDefault {
 
 /// Represents the scope of bot commands, covering all private chats.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeAllPrivateChats {}
 impl BotCommandScopeAllPrivateChats {
     pub fn new() -> Self {
@@ -1902,6 +3725,7 @@ impl BotCommandScopeAllPrivateChats {
 
 /// Represents the scope of bot commands, covering all group and supergroup chats.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeAllGroupChats {}
 impl BotCommandScopeAllGroupChats {
     pub fn new() -> Self {
@@ -1911,6 +3735,7 @@ impl BotCommandScopeAllGroupChats {
 
 /// Represents the scope of bot commands, covering all group and supergroup chat administrators.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeAllChatAdministrators {}
 impl BotCommandScopeAllChatAdministrators {
     pub fn new() -> Self {
@@ -1920,6 +3745,7 @@ impl BotCommandScopeAllChatAdministrators {
 
 /// Represents the scope of bot commands, covering a specific chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeChat {
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatId,
@@ -1932,6 +3758,7 @@ impl BotCommandScopeChat {
 
 /// Represents the scope of bot commands, covering all administrators of a specific group or supergroup chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeChatAdministrators {
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatId,
@@ -1944,6 +3771,7 @@ impl BotCommandScopeChatAdministrators {
 
 /// Represents the scope of bot commands, covering a specific member of a group or supergroup chat.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BotCommandScopeChatMember {
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatId,
@@ -1956,8 +3784,48 @@ impl BotCommandScopeChatMember {
     }
 }
 
+/// This object represents the bot's name.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotName {
+    /// The bot's name
+    pub name: String,
+}
+impl BotName {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+/// This object represents the bot's description.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotDescription {
+    /// The bot's description
+    pub description: String,
+}
+impl BotDescription {
+    pub fn new(description: String) -> Self {
+        Self { description }
+    }
+}
+
+/// This object represents the bot's short description.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotShortDescription {
+    /// The bot's short description
+    pub short_description: String,
+}
+impl BotShortDescription {
+    pub fn new(short_description: String) -> Self {
+        Self { short_description }
+    }
+}
+
 /// Represents a menu button, which opens the bot's list of commands.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MenuButtonCommands {}
 impl MenuButtonCommands {
     pub fn new() -> Self {
@@ -1967,6 +3835,7 @@ impl MenuButtonCommands {
 
 /// Represents a menu button, which launches a Web App.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MenuButtonWebApp {
     /// Text on the button
     pub text: String,
@@ -1981,6 +3850,7 @@ impl MenuButtonWebApp {
 
 /// Describes that no specific value for the menu button was set.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MenuButtonDefault {}
 impl MenuButtonDefault {
     pub fn new() -> Self {
@@ -1990,6 +3860,7 @@ impl MenuButtonDefault {
 
 /// Describes why a request was unsuccessful.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ResponseParameters {
     /// Optional. The group has been migrated to a supergroup with the specified identifier. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2009,6 +3880,7 @@ impl ResponseParameters {
 
 /// Represents a photo to be sent.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputMediaPhoto {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: InputFile,
@@ -2021,6 +3893,12 @@ pub struct InputMediaPhoto {
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Optional. Pass True if the photo needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
 }
 impl InputMediaPhoto {
     pub fn new(media: InputFile) -> Self {
@@ -2029,18 +3907,28 @@ impl InputMediaPhoto {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
         }
     }
+    /// from_path builds the photo from a local file that will be uploaded.
+    pub fn from_path(path: String) -> Self {
+        Self::new(InputFile::FilePath(path))
+    }
 }
 
 /// Represents a video to be sent.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputMediaVideo {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: InputFile,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
     pub thumb: Option<InputFile>,
+    /// Optional. The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<InputFile>,
     /// Optional. Caption of the video to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -2050,6 +3938,9 @@ pub struct InputMediaVideo {
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
     /// Optional. Video width
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<i64>,
@@ -2062,31 +3953,45 @@ pub struct InputMediaVideo {
     /// Optional. Pass True if the uploaded video is suitable for streaming
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supports_streaming: Option<bool>,
+    /// Optional. Pass True if the video needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
 }
 impl InputMediaVideo {
     pub fn new(media: InputFile) -> Self {
         Self {
             media,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             width: None,
             height: None,
             duration: None,
             supports_streaming: None,
+            has_spoiler: None,
         }
     }
+    /// from_path builds the video from a local file that will be uploaded.
+    pub fn from_path(path: String) -> Self {
+        Self::new(InputFile::FilePath(path))
+    }
 }
 
 /// Represents an animation file (GIF or H.264/MPEG-4 AVC video without sound) to be sent.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputMediaAnimation {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: InputFile,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
     pub thumb: Option<InputFile>,
+    /// Optional. The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<InputFile>,
     /// Optional. Caption of the animation to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -2096,6 +4001,9 @@ pub struct InputMediaAnimation {
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
     /// Optional. Animation width
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<i64>,
@@ -2105,30 +4013,44 @@ pub struct InputMediaAnimation {
     /// Optional. Animation duration in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
+    /// Optional. Pass True if the animation needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
 }
 impl InputMediaAnimation {
     pub fn new(media: InputFile) -> Self {
         Self {
             media,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             width: None,
             height: None,
             duration: None,
+            has_spoiler: None,
         }
     }
+    /// from_path builds the animation from a local file that will be uploaded.
+    pub fn from_path(path: String) -> Self {
+        Self::new(InputFile::FilePath(path))
+    }
 }
 
 /// Represents an audio file to be treated as music to be sent.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputMediaAudio {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: InputFile,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
     pub thumb: Option<InputFile>,
+    /// Optional. The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<InputFile>,
     /// Optional. Caption of the audio to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -2153,6 +4075,7 @@ impl InputMediaAudio {
         Self {
             media,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
@@ -2161,16 +4084,24 @@ impl InputMediaAudio {
             title: None,
         }
     }
+    /// from_path builds the audio from a local file that will be uploaded.
+    pub fn from_path(path: String) -> Self {
+        Self::new(InputFile::FilePath(path))
+    }
 }
 
 /// Represents a general file to be sent.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputMediaDocument {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: InputFile,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
     pub thumb: Option<InputFile>,
+    /// Optional. The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<InputFile>,
     /// Optional. Caption of the document to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -2189,16 +4120,22 @@ impl InputMediaDocument {
         Self {
             media,
             thumb: None,
+            legacy_thumb: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
             disable_content_type_detection: None,
         }
     }
+    /// from_path builds the document from a local file that will be uploaded.
+    pub fn from_path(path: String) -> Self {
+        Self::new(InputFile::FilePath(path))
+    }
 }
 
 /// This object represents a sticker.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Sticker {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
@@ -2216,7 +4153,10 @@ pub struct Sticker {
     /// True, if the sticker is a video sticker
     pub is_video: bool,
     /// Optional. Sticker thumbnail in the .WEBP or .JPG format
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
     /// Optional. Emoji associated with the sticker
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2233,8 +4173,15 @@ pub struct Sticker {
     /// Optional. For custom emoji stickers, unique identifier of the custom emoji
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_emoji_id: Option<String>,
-    /// Optional. File size in bytes
+    /// Optional. True, if the sticker must be repainted to a text color in messages, the color of the Telegram Premium badge in emoji status, white color on chat photos, or another appropriate color in other places
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_repainting: Option<bool>,
+    /// Optional. File size in bytes
+    #[serde(
+        default,
+        deserialize_with = "deserialize_file_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub file_size: Option<i64>,
 }
 impl Sticker {
@@ -2255,19 +4202,26 @@ impl Sticker {
             height,
             is_animated,
             is_video,
+            thumbnail: None,
             thumb: None,
             emoji: None,
             set_name: None,
             premium_animation: None,
             mask_position: None,
             custom_emoji_id: None,
+            needs_repainting: None,
             file_size: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
 }
 
 /// This object represents a sticker set.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct StickerSet {
     /// Sticker set name
     pub name: String,
@@ -2282,7 +4236,10 @@ pub struct StickerSet {
     /// List of all set stickers
     pub stickers: Vec<Sticker>,
     /// Optional. Sticker set thumbnail in the .WEBP, .TGS, or .WEBM format
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+    /// Optional. The same thumbnail under its name before Bot API 6.6, still sent by some servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 }
 impl StickerSet {
@@ -2301,13 +4258,19 @@ impl StickerSet {
             is_animated,
             is_video,
             stickers,
+            thumbnail: None,
             thumb: None,
         }
     }
+    /// Thumbnail of the file, preferring `thumbnail` over the legacy `thumb` when both are sent
+    pub fn thumbnail(&self) -> Option<&PhotoSize> {
+        self.thumbnail.as_ref().or(self.thumb.as_ref())
+    }
 }
 
 /// This object describes the position on faces where a mask should be placed by default.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MaskPosition {
     /// The part of the face relative to which the mask should be placed. One of “forehead”, “eyes”, “mouth”, or “chin”.
     pub point: String,
@@ -2329,8 +4292,55 @@ impl MaskPosition {
     }
 }
 
+/// This object describes a sticker to be added to a sticker set.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputSticker {
+    /// The added sticker. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. Animated and video stickers can't be uploaded via HTTP URL.
+    pub sticker: InputFile,
+    /// Format of the added sticker, must be one of “static” for a .WEBP or .PNG image, “animated” for a .TGS animation, “video” for a WEBM video
+    pub format: String,
+    /// List of 1-20 emoji associated with the sticker
+    pub emoji_list: Vec<String>,
+    /// Optional. Position where the mask should be placed on faces. For “mask” stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+    /// Optional. List of 0-20 search keywords for the sticker with total length of up to 64 characters. For “regular” and “custom_emoji” stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+}
+impl InputSticker {
+    pub fn new(sticker: InputFile, format: String, emoji_list: Vec<String>) -> Self {
+        Self {
+            sticker,
+            format,
+            emoji_list,
+            mask_position: None,
+            keywords: None,
+        }
+    }
+
+    /// attach_file_name returns the multipart field name the sticker at idx is uploaded under.
+    pub fn attach_file_name(idx: usize) -> String {
+        format!("sticker-{}", idx)
+    }
+
+    /// prepare_input_sticker_param replaces a sticker that needs uploading with a reference to
+    /// the multipart field named by attach_file_name.
+    pub fn prepare_input_sticker_param(&self, idx: usize) -> Self {
+        if !self.sticker.need_upload() {
+            return self.clone();
+        }
+        Self {
+            sticker: InputFile::FileAttach(format!("attach://{}", Self::attach_file_name(idx))),
+            ..self.clone()
+        }
+    }
+}
+
 /// This object represents an incoming inline query. When the user sends an empty query, your bot could return some default or trending results.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQuery {
     /// Unique identifier for this query
     pub id: String,
@@ -2358,10 +4368,19 @@ impl InlineQuery {
             location: None,
         }
     }
+    /// answer creates an AnswerInlineQuery for this query with the given results.
+    pub fn answer(&self, results: Vec<InlineQueryResult>) -> crate::methods::AnswerInlineQuery {
+        crate::methods::AnswerInlineQuery::new(self.id.clone(), results)
+    }
+    /// answer_empty creates an AnswerInlineQuery for this query that shows no results.
+    pub fn answer_empty(&self) -> crate::methods::AnswerInlineQuery {
+        self.answer(Vec::new())
+    }
 }
 
 /// Represents a link to an article or web page.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultArticle {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -2410,6 +4429,7 @@ impl InlineQueryResultArticle {
 
 /// Represents a link to a photo. By default, this photo will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the photo.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultPhoto {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2466,6 +4486,7 @@ impl InlineQueryResultPhoto {
 
 /// Represents a link to an animated GIF file. By default, this animated GIF file will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultGif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2526,6 +4547,7 @@ impl InlineQueryResultGif {
 
 /// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound). By default, this animated MPEG-4 file will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultMpeg4Gif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2586,6 +4608,7 @@ impl InlineQueryResultMpeg4Gif {
 
 /// Represents a link to a page containing an embedded video player or a video file. By default, this video file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the video.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultVideo {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2654,6 +4677,7 @@ impl InlineQueryResultVideo {
 
 /// Represents a link to an MP3 audio file. By default, this audio file will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the audio.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultAudio {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2702,6 +4726,7 @@ impl InlineQueryResultAudio {
 
 /// Represents a link to a voice recording in an .OGG container encoded with OPUS. By default, this voice recording will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the the voice message.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultVoice {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2746,6 +4771,7 @@ impl InlineQueryResultVoice {
 
 /// Represents a link to a file. By default, this file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the file. Currently, only .PDF and .ZIP files can be sent using this method.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultDocument {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2805,6 +4831,7 @@ impl InlineQueryResultDocument {
 
 /// Represents a location on a map. By default, the location will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the location.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultLocation {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -2864,6 +4891,7 @@ impl InlineQueryResultLocation {
 
 /// Represents a venue. By default, the venue will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the venue.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultVenue {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -2926,6 +4954,7 @@ impl InlineQueryResultVenue {
 
 /// Represents a contact with a phone number. By default, this contact will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the contact.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultContact {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -2974,6 +5003,7 @@ impl InlineQueryResultContact {
 
 /// Represents a Game.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultGame {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -2995,6 +5025,7 @@ impl InlineQueryResultGame {
 
 /// Represents a link to a photo stored on the Telegram servers. By default, this photo will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the photo.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedPhoto {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3040,6 +5071,7 @@ impl InlineQueryResultCachedPhoto {
 
 /// Represents a link to an animated GIF file stored on the Telegram servers. By default, this animated GIF file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with specified content instead of the animation.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedGif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3081,6 +5113,7 @@ impl InlineQueryResultCachedGif {
 
 /// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound) stored on the Telegram servers. By default, this animated MPEG-4 file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedMpeg4Gif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3122,6 +5155,7 @@ impl InlineQueryResultCachedMpeg4Gif {
 
 /// Represents a link to a sticker stored on the Telegram servers. By default, this sticker will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the sticker.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedSticker {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3130,7 +5164,8 @@ pub struct InlineQueryResultCachedSticker {
     /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the sticker,
+    /// Optional. Content of the message to be sent instead of the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 impl InlineQueryResultCachedSticker {
@@ -3146,6 +5181,7 @@ impl InlineQueryResultCachedSticker {
 
 /// Represents a link to a file stored on the Telegram servers. By default, this file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the file.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedDocument {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3190,6 +5226,7 @@ impl InlineQueryResultCachedDocument {
 
 /// Represents a link to a video file stored on the Telegram servers. By default, this video file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the video.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedVideo {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3234,6 +5271,7 @@ impl InlineQueryResultCachedVideo {
 
 /// Represents a link to a voice message stored on the Telegram servers. By default, this voice message will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the voice message.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedVoice {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3274,6 +5312,7 @@ impl InlineQueryResultCachedVoice {
 
 /// Represents a link to an MP3 audio file stored on the Telegram servers. By default, this audio file will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the audio.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQueryResultCachedAudio {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
@@ -3311,6 +5350,7 @@ impl InlineQueryResultCachedAudio {
 
 /// Represents the content of a text message to be sent as the result of an inline query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputTextMessageContent {
     /// Text of the message to be sent, 1-4096 characters
     pub message_text: String,
@@ -3337,6 +5377,7 @@ impl InputTextMessageContent {
 
 /// Represents the content of a location message to be sent as the result of an inline query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputLocationMessageContent {
     /// Latitude of the location in degrees
     pub latitude: f64,
@@ -3366,10 +5407,114 @@ impl InputLocationMessageContent {
             proximity_alert_radius: None,
         }
     }
+
+    /// validate checks the coordinates and live location options against the ranges accepted by the server.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_location(
+            self.latitude,
+            self.longitude,
+            self.live_period,
+            self.heading,
+            self.proximity_alert_radius,
+        )
+    }
+}
+
+/// validate_caption_formatting rejects setting both parse_mode and entities: Telegram ignores
+/// parse_mode when entities are given, so sending both is almost certainly a mistake.
+pub fn validate_caption_formatting(
+    parse_mode: &Option<String>,
+    entities: &Option<Vec<MessageEntity>>,
+) -> Result<(), ValidationError> {
+    if parse_mode.is_some() && entities.is_some() {
+        return Err(ValidationError::new(
+            "parse_mode".to_string(),
+            "parse_mode is ignored when caption_entities is set, use only one of them".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// A thumbnail must be a JPEG of at most 200 kB, with width and height not exceeding 320.
+pub const MAX_THUMBNAIL_SIZE: usize = 200 * 1024;
+
+/// validate_thumb checks an in-memory thumbnail against the size limit shared by sendAudio,
+/// sendDocument, sendVideo, sendAnimation and sendVideoNote. Other variants are left to the
+/// server, as are the format and dimension limits, which would require decoding the image.
+pub fn validate_thumb(thumb: &Option<InputFile>) -> Result<(), ValidationError> {
+    if let Some(InputFile::FileBytes(_, bytes)) = thumb {
+        if bytes.len() > MAX_THUMBNAIL_SIZE {
+            return Err(ValidationError::new(
+                "thumb".to_string(),
+                format!(
+                    "thumb is {} bytes, must be at most {} bytes",
+                    bytes.len(),
+                    MAX_THUMBNAIL_SIZE
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// validate_location checks the fields shared by sendLocation, editMessageLiveLocation and
+/// InputLocationMessageContent. live_period must be 60-86400, heading 1-360 and
+/// proximity_alert_radius 1-100000; latitude and longitude must be valid coordinates.
+pub(crate) fn validate_location(
+    latitude: f64,
+    longitude: f64,
+    live_period: Option<i64>,
+    heading: Option<i64>,
+    proximity_alert_radius: Option<i64>,
+) -> Result<(), ValidationError> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(ValidationError::new(
+            "latitude".to_string(),
+            format!("latitude is {}, must be between -90 and 90", latitude),
+        ));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(ValidationError::new(
+            "longitude".to_string(),
+            format!("longitude is {}, must be between -180 and 180", longitude),
+        ));
+    }
+    if let Some(live_period) = live_period {
+        if !(60..=86400).contains(&live_period) {
+            return Err(ValidationError::new(
+                "live_period".to_string(),
+                format!(
+                    "live_period is {}, must be between 60 and 86400",
+                    live_period
+                ),
+            ));
+        }
+    }
+    if let Some(heading) = heading {
+        if !(1..=360).contains(&heading) {
+            return Err(ValidationError::new(
+                "heading".to_string(),
+                format!("heading is {}, must be between 1 and 360", heading),
+            ));
+        }
+    }
+    if let Some(radius) = proximity_alert_radius {
+        if !(1..=100000).contains(&radius) {
+            return Err(ValidationError::new(
+                "proximity_alert_radius".to_string(),
+                format!(
+                    "proximity_alert_radius is {}, must be between 1 and 100000",
+                    radius
+                ),
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// Represents the content of a venue message to be sent as the result of an inline query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputVenueMessageContent {
     /// Latitude of the venue in degrees
     pub latitude: f64,
@@ -3409,6 +5554,7 @@ impl InputVenueMessageContent {
 
 /// Represents the content of a contact message to be sent as the result of an inline query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputContactMessageContent {
     /// Contact's phone number
     pub phone_number: String,
@@ -3434,6 +5580,7 @@ impl InputContactMessageContent {
 
 /// Represents the content of an invoice message to be sent as the result of an inline query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InputInvoiceMessageContent {
     /// Product name, 1-32 characters
     pub title: String,
@@ -3526,6 +5673,7 @@ impl InputInvoiceMessageContent {
 
 /// Represents a result of an inline query that was chosen by the user and sent to their chat partner.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChosenInlineResult {
     /// The unique identifier for the result that was chosen
     pub result_id: String,
@@ -3554,6 +5702,7 @@ impl ChosenInlineResult {
 
 /// Describes an inline message sent by a Web App on behalf of a user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct SentWebAppMessage {
     /// Optional. Identifier of the sent inline message. Available only if there is an inline keyboard attached to the message.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3567,22 +5716,50 @@ impl SentWebAppMessage {
     }
 }
 
+/// Describes an inline message to be sent by a user of a Mini App.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PreparedInlineMessage {
+    /// Unique identifier of the prepared message
+    pub id: String,
+    /// Expiration date of the prepared message, in Unix time. Expired prepared messages can no longer be used
+    pub expiration_date: i64,
+}
+impl PreparedInlineMessage {
+    pub fn new(id: String, expiration_date: i64) -> Self {
+        Self {
+            id,
+            expiration_date,
+        }
+    }
+}
+
 /// This object represents a portion of the price for goods or services.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct LabeledPrice {
     /// Portion label
     pub label: String,
     /// Price of the product in the smallest units of the currency (integer, not float/double). For example, for a price of US$ 1.45 pass amount = 145. See the exp parameter in currencies.json, it shows the number of digits past the decimal point for each currency (2 for the majority of currencies).
     pub amount: i64,
 }
+/// Currency code used for payments in Telegram Stars.
+pub const STARS_CURRENCY: &str = "XTR";
+
 impl LabeledPrice {
     pub fn new(label: String, amount: i64) -> Self {
         Self { label, amount }
     }
+    /// stars creates a price for an invoice in Telegram Stars (currency "XTR").
+    /// XTR has no decimal exponent, so amount is the whole number of stars.
+    pub fn stars(label: String, amount: i64) -> Self {
+        Self::new(label, amount)
+    }
 }
 
 /// This object contains basic information about an invoice.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Invoice {
     /// Product name
     pub title: String,
@@ -3611,10 +5788,45 @@ impl Invoice {
             total_amount,
         }
     }
+    /// formatted_total returns total_amount in major units of the currency, e.g. "1.45" for 145 USD.
+    pub fn formatted_total(&self) -> String {
+        format_amount(&self.currency, self.total_amount)
+    }
+}
+
+/// currency_exponent returns the number of digits past the decimal point for an ISO 4217 currency code,
+/// as listed in Telegram's currencies.json. Unknown currencies default to 2.
+pub fn currency_exponent(currency: &str) -> u32 {
+    match currency {
+        "XTR" | "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+        | "UGX" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// format_amount formats an amount given in the smallest units of currency, e.g. "1.45" for (USD, 145)
+/// and "500" for (JPY, 500).
+pub fn format_amount(currency: &str, amount: i64) -> String {
+    let exponent = currency_exponent(currency);
+    if exponent == 0 {
+        return amount.to_string();
+    }
+    let divisor = 10_u64.pow(exponent);
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.unsigned_abs();
+    format!(
+        "{}{}.{:0width$}",
+        sign,
+        amount / divisor,
+        amount % divisor,
+        width = exponent as usize
+    )
 }
 
 /// This object represents a shipping address.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ShippingAddress {
     /// Two-letter ISO 3166-1 alpha-2 country code
     pub country_code: String,
@@ -3651,6 +5863,7 @@ impl ShippingAddress {
 
 /// This object represents information about an order.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct OrderInfo {
     /// Optional. User name
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3678,6 +5891,7 @@ impl OrderInfo {
 
 /// This object represents one shipping option.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ShippingOption {
     /// Shipping option identifier
     pub id: String,
@@ -3694,46 +5908,256 @@ impl ShippingOption {
 
 /// This object contains basic information about a successful payment.
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct SuccessfulPayment {
-    /// Three-letter ISO 4217 currency code
-    pub currency: String,
-    /// Total price in the smallest units of the currency (integer, not float/double). For example, for a price of US$ 1.45 pass amount = 145. See the exp parameter in currencies.json, it shows the number of digits past the decimal point for each currency (2 for the majority of currencies).
-    pub total_amount: i64,
-    /// Bot specified invoice payload
-    pub invoice_payload: String,
-    /// Optional. Identifier of the shipping option chosen by the user
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not float/double). For example, for a price of US$ 1.45 pass amount = 145. See the exp parameter in currencies.json, it shows the number of digits past the decimal point for each currency (2 for the majority of currencies).
+    pub total_amount: i64,
+    /// Optional. Expiration date of the subscription, in Unix time; for recurring payments only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_expiration_date: Option<i64>,
+    /// Optional. True, if the payment is a recurring payment for a subscription
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_recurring: Option<bool>,
+    /// Optional. True, if the payment is the first payment for a subscription
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_recurring: Option<bool>,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    /// Optional. Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+    /// Optional. Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Provider payment identifier
+    pub provider_payment_charge_id: String,
+}
+impl SuccessfulPayment {
+    pub fn new(
+        currency: String,
+        total_amount: i64,
+        invoice_payload: String,
+        telegram_payment_charge_id: String,
+        provider_payment_charge_id: String,
+    ) -> Self {
+        Self {
+            currency,
+            total_amount,
+            subscription_expiration_date: None,
+            is_recurring: None,
+            is_first_recurring: None,
+            invoice_payload,
+            shipping_option_id: None,
+            order_info: None,
+            telegram_payment_charge_id,
+            provider_payment_charge_id,
+        }
+    }
+    /// is_stars reports whether the payment was made in Telegram Stars.
+    pub fn is_stars(&self) -> bool {
+        self.currency == STARS_CURRENCY
+    }
+    /// subscription returns the subscription details of a recurring payment, or None for one-off payments.
+    /// The telegram_payment_charge_id identifies the subscription in EditUserStarSubscription and RefundStarPayment.
+    pub fn subscription(&self) -> Option<StarSubscription> {
+        if self.is_recurring != Some(true) {
+            return None;
+        }
+        Some(StarSubscription {
+            telegram_payment_charge_id: self.telegram_payment_charge_id.clone(),
+            expiration_date: self.subscription_expiration_date,
+            is_first_recurring: self.is_first_recurring.unwrap_or(false),
+        })
+    }
+}
+
+/// StarSubscription summarizes the subscription fields of a recurring SuccessfulPayment.
+#[derive(Debug, Clone)]
+pub struct StarSubscription {
+    /// Telegram payment identifier of the subscription payment
+    pub telegram_payment_charge_id: String,
+    /// Expiration date of the subscription, in Unix time, if known
+    pub expiration_date: Option<i64>,
+    /// True, if this is the first payment for the subscription
+    pub is_first_recurring: bool,
+}
+
+/// The withdrawal is in progress.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevenueWithdrawalStatePending {}
+impl RevenueWithdrawalStatePending {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// The withdrawal succeeded.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevenueWithdrawalStateSucceeded {
+    /// Date the withdrawal was completed in Unix time
+    pub date: i64,
+    /// An HTTPS URL that can be used to see transaction details
+    pub url: String,
+}
+impl RevenueWithdrawalStateSucceeded {
+    pub fn new(date: i64, url: String) -> Self {
+        Self { date, url }
+    }
+}
+
+/// The withdrawal failed and the transaction was refunded.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RevenueWithdrawalStateFailed {}
+impl RevenueWithdrawalStateFailed {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// This object describes the state of a revenue withdrawal operation. Currently, it can be one of
+/// ```text
+/// RevenueWithdrawalStatePending
+/// RevenueWithdrawalStateSucceeded
+/// RevenueWithdrawalStateFailed
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum RevenueWithdrawalState {
+    #[serde(rename = "pending")]
+    RevenueWithdrawalStatePending(RevenueWithdrawalStatePending),
+    #[serde(rename = "succeeded")]
+    RevenueWithdrawalStateSucceeded(RevenueWithdrawalStateSucceeded),
+    #[serde(rename = "failed")]
+    RevenueWithdrawalStateFailed(RevenueWithdrawalStateFailed),
+}
+
+/// Describes a transaction with a user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerUser {
+    /// Information about the user
+    pub user: User,
+    /// Optional. Bot-specified invoice payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_payload: Option<String>,
+}
+impl TransactionPartnerUser {
+    pub fn new(user: User) -> Self {
+        Self {
+            user,
+            invoice_payload: None,
+        }
+    }
+}
+
+/// Describes a withdrawal transaction with Fragment.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerFragment {
+    /// Optional. State of the transaction if the transaction is outgoing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawal_state: Option<RevenueWithdrawalState>,
+}
+impl TransactionPartnerFragment {
+    pub fn new() -> Self {
+        Self {
+            withdrawal_state: None,
+        }
+    }
+}
+
+/// Describes a withdrawal transaction to the Telegram Ads platform.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerTelegramAds {}
+impl TransactionPartnerTelegramAds {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Describes a transaction with an unknown source or recipient.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionPartnerOther {}
+impl TransactionPartnerOther {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// This object describes the source of a transaction, or its recipient for outgoing transactions. Currently, it can be one of
+/// ```text
+/// TransactionPartnerUser
+/// TransactionPartnerFragment
+/// TransactionPartnerTelegramAds
+/// TransactionPartnerOther
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum TransactionPartner {
+    #[serde(rename = "user")]
+    TransactionPartnerUser(TransactionPartnerUser),
+    #[serde(rename = "fragment")]
+    TransactionPartnerFragment(TransactionPartnerFragment),
+    #[serde(rename = "telegram_ads")]
+    TransactionPartnerTelegramAds(TransactionPartnerTelegramAds),
+    #[serde(rename = "other")]
+    TransactionPartnerOther(TransactionPartnerOther),
+}
+
+/// Describes a Telegram Star transaction.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifier of the original transaction for refund transactions. Coincides with SuccessfulPayment.telegram_payment_charge_id for successful incoming payments from users.
+    pub id: String,
+    /// Number of Telegram Stars transferred by the transaction
+    pub amount: i64,
+    /// Date the transaction was created in Unix time
+    pub date: i64,
+    /// Optional. Source of an incoming transaction (e.g., a user purchasing goods or services, Fragment refunding a failed withdrawal). Only for incoming transactions
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option_id: Option<String>,
-    /// Optional. Order information provided by the user
+    pub source: Option<TransactionPartner>,
+    /// Optional. Receiver of an outgoing transaction (e.g., a user for a purchase refund, Fragment for a withdrawal). Only for outgoing transactions
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_info: Option<OrderInfo>,
-    /// Telegram payment identifier
-    pub telegram_payment_charge_id: String,
-    /// Provider payment identifier
-    pub provider_payment_charge_id: String,
+    pub receiver: Option<TransactionPartner>,
 }
-impl SuccessfulPayment {
-    pub fn new(
-        currency: String,
-        total_amount: i64,
-        invoice_payload: String,
-        telegram_payment_charge_id: String,
-        provider_payment_charge_id: String,
-    ) -> Self {
+impl StarTransaction {
+    pub fn new(id: String, amount: i64, date: i64) -> Self {
         Self {
-            currency,
-            total_amount,
-            invoice_payload,
-            shipping_option_id: None,
-            order_info: None,
-            telegram_payment_charge_id,
-            provider_payment_charge_id,
+            id,
+            amount,
+            date,
+            source: None,
+            receiver: None,
         }
     }
 }
 
+/// Contains a list of Telegram Star transactions.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StarTransactions {
+    /// The list of transactions
+    pub transactions: Vec<StarTransaction>,
+}
+impl StarTransactions {
+    pub fn new(transactions: Vec<StarTransaction>) -> Self {
+        Self { transactions }
+    }
+}
+
 /// This object contains information about an incoming shipping query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ShippingQuery {
     /// Unique query identifier
     pub id: String,
@@ -3758,10 +6182,23 @@ impl ShippingQuery {
             shipping_address,
         }
     }
+    /// answer_ok creates an AnswerShippingQuery accepting the address with the available shipping options.
+    pub fn answer_ok(&self, options: Vec<ShippingOption>) -> crate::methods::AnswerShippingQuery {
+        let mut answer = crate::methods::AnswerShippingQuery::new(self.id.clone(), true);
+        answer.shipping_options = Some(options);
+        answer
+    }
+    /// answer_error creates an AnswerShippingQuery rejecting the address, showing message to the user.
+    pub fn answer_error(&self, message: String) -> crate::methods::AnswerShippingQuery {
+        let mut answer = crate::methods::AnswerShippingQuery::new(self.id.clone(), false);
+        answer.error_message = Some(message);
+        answer
+    }
 }
 
 /// This object contains information about an incoming pre-checkout query.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PreCheckoutQuery {
     /// Unique query identifier
     pub id: String,
@@ -3798,10 +6235,21 @@ impl PreCheckoutQuery {
             order_info: None,
         }
     }
+    /// answer_ok creates an AnswerPreCheckoutQuery confirming the order is ready to proceed.
+    pub fn answer_ok(&self) -> crate::methods::AnswerPreCheckoutQuery {
+        crate::methods::AnswerPreCheckoutQuery::new(self.id.clone(), true)
+    }
+    /// answer_error creates an AnswerPreCheckoutQuery declining the checkout, showing message to the user.
+    pub fn answer_error(&self, message: String) -> crate::methods::AnswerPreCheckoutQuery {
+        let mut answer = crate::methods::AnswerPreCheckoutQuery::new(self.id.clone(), false);
+        answer.error_message = Some(message);
+        answer
+    }
 }
 
 /// Describes Telegram Passport data shared with the bot by the user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportData {
     /// Array with information about documents and other Telegram Passport elements that was shared with the bot
     pub data: Vec<EncryptedPassportElement>,
@@ -3816,6 +6264,7 @@ impl PassportData {
 
 /// This object represents a file uploaded to Telegram Passport. Currently all Telegram Passport files are in JPEG format when decrypted and don't exceed 10MB.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportFile {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: String,
@@ -3839,6 +6288,7 @@ impl PassportFile {
 
 /// Describes documents or other Telegram Passport elements shared with the bot by the user.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct EncryptedPassportElement {
     /// Element type. One of “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport”, “address”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”, “phone_number”, “email”.
     #[serde(rename = "type")]
@@ -3889,6 +6339,7 @@ impl EncryptedPassportElement {
 
 /// Describes data required for decrypting and authenticating EncryptedPassportElement. See the Telegram Passport Documentation for a complete description of the data decryption and authentication processes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct EncryptedCredentials {
     /// Base64-encoded encrypted JSON-serialized data with unique user's payload, data hashes and secrets required for EncryptedPassportElement decryption and authentication
     pub data: String,
@@ -3905,6 +6356,7 @@ impl EncryptedCredentials {
 
 /// Represents an issue in one of the data fields that was provided by the user. The error is considered resolved when the field's value changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorDataField {
     /// Error source, must be data
     pub source: String,
@@ -3919,15 +6371,12 @@ pub struct PassportElementErrorDataField {
     pub message: String,
 }
 impl PassportElementErrorDataField {
-    pub fn new(
-        source: String,
-        type_name: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "data";
+
+    pub fn new(type_name: String, field_name: String, data_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             field_name,
             data_hash,
@@ -3938,6 +6387,7 @@ impl PassportElementErrorDataField {
 
 /// Represents an issue with the front side of a document. The error is considered resolved when the file with the front side of the document changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorFrontSide {
     /// Error source, must be front_side
     pub source: String,
@@ -3950,9 +6400,12 @@ pub struct PassportElementErrorFrontSide {
     pub message: String,
 }
 impl PassportElementErrorFrontSide {
-    pub fn new(source: String, type_name: String, file_hash: String, message: String) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "front_side";
+
+    pub fn new(type_name: String, file_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hash,
             message,
@@ -3962,6 +6415,7 @@ impl PassportElementErrorFrontSide {
 
 /// Represents an issue with the reverse side of a document. The error is considered resolved when the file with reverse side of the document changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorReverseSide {
     /// Error source, must be reverse_side
     pub source: String,
@@ -3974,9 +6428,12 @@ pub struct PassportElementErrorReverseSide {
     pub message: String,
 }
 impl PassportElementErrorReverseSide {
-    pub fn new(source: String, type_name: String, file_hash: String, message: String) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "reverse_side";
+
+    pub fn new(type_name: String, file_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hash,
             message,
@@ -3986,6 +6443,7 @@ impl PassportElementErrorReverseSide {
 
 /// Represents an issue with the selfie with a document. The error is considered resolved when the file with the selfie changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorSelfie {
     /// Error source, must be selfie
     pub source: String,
@@ -3998,9 +6456,12 @@ pub struct PassportElementErrorSelfie {
     pub message: String,
 }
 impl PassportElementErrorSelfie {
-    pub fn new(source: String, type_name: String, file_hash: String, message: String) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "selfie";
+
+    pub fn new(type_name: String, file_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hash,
             message,
@@ -4010,6 +6471,7 @@ impl PassportElementErrorSelfie {
 
 /// Represents an issue with a document scan. The error is considered resolved when the file with the document scan changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorFile {
     /// Error source, must be file
     pub source: String,
@@ -4022,9 +6484,12 @@ pub struct PassportElementErrorFile {
     pub message: String,
 }
 impl PassportElementErrorFile {
-    pub fn new(source: String, type_name: String, file_hash: String, message: String) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "file";
+
+    pub fn new(type_name: String, file_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hash,
             message,
@@ -4034,6 +6499,7 @@ impl PassportElementErrorFile {
 
 /// Represents an issue with a list of scans. The error is considered resolved when the list of files containing the scans changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorFiles {
     /// Error source, must be files
     pub source: String,
@@ -4046,14 +6512,12 @@ pub struct PassportElementErrorFiles {
     pub message: String,
 }
 impl PassportElementErrorFiles {
-    pub fn new(
-        source: String,
-        type_name: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "files";
+
+    pub fn new(type_name: String, file_hashes: Vec<String>, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hashes,
             message,
@@ -4063,6 +6527,7 @@ impl PassportElementErrorFiles {
 
 /// Represents an issue with one of the files that constitute the translation of a document. The error is considered resolved when the file changes.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorTranslationFile {
     /// Error source, must be translation_file
     pub source: String,
@@ -4075,9 +6540,12 @@ pub struct PassportElementErrorTranslationFile {
     pub message: String,
 }
 impl PassportElementErrorTranslationFile {
-    pub fn new(source: String, type_name: String, file_hash: String, message: String) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "translation_file";
+
+    pub fn new(type_name: String, file_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hash,
             message,
@@ -4087,6 +6555,7 @@ impl PassportElementErrorTranslationFile {
 
 /// Represents an issue with the translated version of a document. The error is considered resolved when a file with the document translation change.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorTranslationFiles {
     /// Error source, must be translation_files
     pub source: String,
@@ -4099,14 +6568,12 @@ pub struct PassportElementErrorTranslationFiles {
     pub message: String,
 }
 impl PassportElementErrorTranslationFiles {
-    pub fn new(
-        source: String,
-        type_name: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "translation_files";
+
+    pub fn new(type_name: String, file_hashes: Vec<String>, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             file_hashes,
             message,
@@ -4116,6 +6583,7 @@ impl PassportElementErrorTranslationFiles {
 
 /// Represents an issue in an unspecified place. The error is considered resolved when new data is added.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PassportElementErrorUnspecified {
     /// Error source, must be unspecified
     pub source: String,
@@ -4128,9 +6596,12 @@ pub struct PassportElementErrorUnspecified {
     pub message: String,
 }
 impl PassportElementErrorUnspecified {
-    pub fn new(source: String, type_name: String, element_hash: String, message: String) -> Self {
+    /// The source every error of this type is reported with.
+    pub const SOURCE: &'static str = "unspecified";
+
+    pub fn new(type_name: String, element_hash: String, message: String) -> Self {
         Self {
-            source,
+            source: Self::SOURCE.to_string(),
             type_name,
             element_hash,
             message,
@@ -4140,6 +6611,7 @@ impl PassportElementErrorUnspecified {
 
 /// This object represents a game. Use BotFather to create and edit games, their short names will act as unique identifiers.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Game {
     /// Title of the game
     pub title: String,
@@ -4172,6 +6644,7 @@ impl Game {
 
 /// A placeholder, currently holds no information. Use BotFather to set up your game.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CallbackGame {}
 impl CallbackGame {
     pub fn new() -> Self {
@@ -4181,6 +6654,7 @@ impl CallbackGame {
 
 /// This object represents one row of the high scores table for a game.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct GameHighScore {
     /// Position in high score table for the game
     pub position: i64,
@@ -4202,6 +6676,39 @@ impl GameHighScore {
 /// Params represents a set of parameters that gets passed to a request.
 pub type Params = HashMap<String, Value>;
 
+/// sorted_params returns the parameters as key-sorted pairs, so that form fields, logs and
+/// signatures built from them don't depend on the HashMap iteration order.
+pub fn sorted_params(params: &Params) -> Vec<(String, Value)> {
+    let mut pairs: Vec<(String, Value)> = params
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    pairs
+}
+
+/// ValidationError is returned when a request fails a local check before it is sent to the Telegram API.
+/// The checks are opt-in: BotApi::send doesn't run them, so call the request's validate() before sending it.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ValidationError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(field: String, message: String) -> Self {
+        Self { field, message }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
 /// Unique identifier for the target chat or username of the target channel
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -4212,6 +6719,31 @@ pub enum ChatId {
     StringType(String),
 }
 
+impl ChatId {
+    /// parse reads a chat id from configuration: numeric ids, including negative group and
+    /// -100 prefixed supergroup and channel ids, become IntType, anything else StringType.
+    pub fn parse(value: &str) -> ChatId {
+        match value.trim().parse::<i64>() {
+            Ok(id) => ChatId::IntType(id),
+            Err(_) => ChatId::StringType(value.trim().to_string()),
+        }
+    }
+    /// as_i64 returns the numeric chat id, if this is one.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            ChatId::IntType(id) => Some(*id),
+            ChatId::StringType(_) => None,
+        }
+    }
+    /// as_username returns the username, as given (usually with the leading @), if this is one.
+    pub fn as_username(&self) -> Option<&str> {
+        match self {
+            ChatId::IntType(_) => None,
+            ChatId::StringType(username) => Some(username),
+        }
+    }
+}
+
 /// This object represents the contents of a file to be uploaded. Must be posted using multipart/form-data in the usual way that files are uploaded via the browser.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -4228,12 +6760,61 @@ pub enum InputFile {
     FilePath(String),
 }
 
+/// On success,returns a InputFilePart object data_with method
+pub enum InputFilePart<P> {
+    /// don't need upload
+    Text(String),
+    /// must upload using multipart/form-data
+    Part(P),
+}
+
 /// On success,returns a InputFileResult object data method
-pub enum InputFileResult {
+#[cfg(feature = "reqwest-backend")]
+pub type InputFileResult = InputFilePart<reqwest::multipart::Part>;
+
+/// FileBackend builds the multipart parts for files that need to be uploaded, so the types
+/// don't depend on a particular HTTP client.
+pub trait FileBackend {
+    /// Part is the multipart part type of the HTTP client.
+    type Part;
+    /// bytes builds a part from in-memory data.
+    fn bytes(&self, file_name: &str, bytes: Vec<u8>) -> Self::Part;
+    /// path builds a part from the local file at path.
+    fn path(
+        &self,
+        path: &str,
+    ) -> impl std::future::Future<Output = Result<Self::Part, Box<dyn std::error::Error>>>;
+}
+
+/// ReqwestBackend streams local files into reqwest multipart parts. It is used by the BotApi client.
+#[cfg(feature = "reqwest-backend")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReqwestBackend;
+
+#[cfg(feature = "reqwest-backend")]
+impl FileBackend for ReqwestBackend {
+    type Part = reqwest::multipart::Part;
+    fn bytes(&self, file_name: &str, bytes: Vec<u8>) -> Self::Part {
+        reqwest::multipart::Part::bytes(bytes).file_name(file_name.to_string())
+    }
+    async fn path(&self, path: &str) -> Result<Self::Part, Box<dyn std::error::Error>> {
+        Ok(reqwest::multipart::Part::stream(reqwest::Body::wrap_stream(
+            tokio_util::codec::FramedRead::new(
+                tokio::fs::File::open(path).await?,
+                tokio_util::codec::BytesCodec::new(),
+            ),
+        ))
+        .file_name(path.to_string()))
+    }
+}
+
+/// InputFileBlockingResult is the request value of a file built by InputFile::data_blocking.
+#[cfg(feature = "blocking")]
+pub enum InputFileBlockingResult {
     /// don't need upload
     Text(String),
     /// must upload using multipart/form-data
-    Part(reqwest::multipart::Part),
+    Part(reqwest::blocking::multipart::Part),
 }
 
 impl InputFile {
@@ -4241,28 +6822,66 @@ impl InputFile {
         matches!(self, InputFile::FileBytes(_, _) | InputFile::FilePath(_))
     }
 
+    /// local_size returns the size in bytes of a FilePath file, or None for other variants
+    /// and for paths that can't be read. Useful to reject files over Telegram's upload limits.
+    #[cfg(feature = "reqwest-backend")]
+    pub async fn local_size(&self) -> Option<u64> {
+        match self {
+            InputFile::FilePath(path) => tokio::fs::metadata(path)
+                .await
+                .ok()
+                .map(|metadata| metadata.len()),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "reqwest-backend")]
     pub async fn data(&self) -> Result<InputFileResult, Box<dyn std::error::Error>> {
+        self.data_with(&ReqwestBackend).await
+    }
+
+    /// data_with builds the request value of the file, using backend for the files that need to be uploaded.
+    pub async fn data_with<B: FileBackend>(
+        &self,
+        backend: &B,
+    ) -> Result<InputFilePart<B::Part>, Box<dyn std::error::Error>> {
+        match self {
+            InputFile::FileID(id) => Ok(InputFilePart::Text(id.clone())),
+            InputFile::FileURL(url) => Ok(InputFilePart::Text(url.clone())),
+            InputFile::FileAttach(attach) => Ok(InputFilePart::Text(attach.clone())),
+            InputFile::FileBytes(file_name, bytes) => {
+                Ok(InputFilePart::Part(backend.bytes(file_name, bytes.clone())))
+            }
+            InputFile::FilePath(path) => Ok(InputFilePart::Part(backend.path(path).await?)),
+        }
+    }
+
+    /// data_blocking is the synchronous counterpart of data, reading local files with std::fs.
+    #[cfg(feature = "blocking")]
+    pub fn data_blocking(&self) -> Result<InputFileBlockingResult, Box<dyn std::error::Error>> {
         match self {
-            InputFile::FileID(id) => Ok(InputFileResult::Text(id.clone())),
-            InputFile::FileURL(url) => Ok(InputFileResult::Text(url.clone())),
-            InputFile::FileAttach(attach) => Ok(InputFileResult::Text(attach.clone())),
-            InputFile::FileBytes(file_name, bytes) => Ok(InputFileResult::Part(
-                reqwest::multipart::Part::bytes(bytes.clone()).file_name(file_name.to_string()),
+            InputFile::FileID(id) => Ok(InputFileBlockingResult::Text(id.clone())),
+            InputFile::FileURL(url) => Ok(InputFileBlockingResult::Text(url.clone())),
+            InputFile::FileAttach(attach) => Ok(InputFileBlockingResult::Text(attach.clone())),
+            InputFile::FileBytes(file_name, bytes) => Ok(InputFileBlockingResult::Part(
+                reqwest::blocking::multipart::Part::bytes(bytes.clone())
+                    .file_name(file_name.to_string()),
             )),
-            InputFile::FilePath(path) => Ok(InputFileResult::Part(
-                reqwest::multipart::Part::stream(reqwest::Body::wrap_stream(
-                    tokio_util::codec::FramedRead::new(
-                        tokio::fs::File::open(path).await?,
-                        tokio_util::codec::BytesCodec::new(),
-                    ),
-                ))
-                .file_name(path.to_string()),
+            InputFile::FilePath(path) => Ok(InputFileBlockingResult::Part(
+                reqwest::blocking::multipart::Part::bytes(std::fs::read(path)?)
+                    .file_name(path.to_string()),
             )),
         }
     }
 }
 
 /// Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
+///
+/// Deserialization tries the variants in declaration order: `InlineKeyboardMarkup`,
+/// `ReplyKeyboardMarkup`, `ReplyKeyboardRemove`, `ForceReply`. Each variant has a
+/// required field the others lack (`inline_keyboard`, `keyboard`, `remove_keyboard`
+/// and `force_reply` respectively), so a serialized value always parses back into
+/// the variant it came from, even when its keyboard is empty.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ReplyMarkup {
@@ -4271,6 +6890,31 @@ pub enum ReplyMarkup {
     ReplyKeyboardRemove(ReplyKeyboardRemove),
     ForceReply(ForceReply),
 }
+
+impl From<InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: InlineKeyboardMarkup) -> Self {
+        ReplyMarkup::InlineKeyboardMarkup(markup)
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardMarkup) -> Self {
+        ReplyMarkup::ReplyKeyboardMarkup(markup)
+    }
+}
+
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(markup)
+    }
+}
+
+impl From<ForceReply> for ReplyMarkup {
+    fn from(markup: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(markup)
+    }
+}
+
 /// This object contains information about one member of a chat. Currently, the following 6 types of chat members are supported:
 /// ```
 /// ChatMemberOwner
@@ -4297,6 +6941,55 @@ pub enum ChatMember {
     ChatMemberBanned(ChatMemberBanned),
 }
 
+impl ChatMember {
+    /// status returns the member's status as sent by the API: “creator”, “administrator”, “member”, “restricted”, “left” or “kicked”.
+    pub fn status(&self) -> &'static str {
+        match self {
+            ChatMember::ChatMemberOwner(_) => "creator",
+            ChatMember::ChatMemberAdministrator(_) => "administrator",
+            ChatMember::ChatMemberMember(_) => "member",
+            ChatMember::ChatMemberRestricted(_) => "restricted",
+            ChatMember::ChatMemberLeft(_) => "left",
+            ChatMember::ChatMemberBanned(_) => "kicked",
+        }
+    }
+    /// is_present reports whether the user is currently in the chat. Restricted users may or may not be members.
+    pub fn is_present(&self) -> bool {
+        match self {
+            ChatMember::ChatMemberRestricted(restricted) => restricted.is_member,
+            member => matches!(member.status(), "creator" | "administrator" | "member"),
+        }
+    }
+    /// user returns information about the user this membership belongs to.
+    pub fn user(&self) -> &User {
+        match self {
+            ChatMember::ChatMemberOwner(member) => &member.user,
+            ChatMember::ChatMemberAdministrator(member) => &member.user,
+            ChatMember::ChatMemberMember(member) => &member.user,
+            ChatMember::ChatMemberRestricted(member) => &member.user,
+            ChatMember::ChatMemberLeft(member) => &member.user,
+            ChatMember::ChatMemberBanned(member) => &member.user,
+        }
+    }
+}
+
+/// This object describes the source of a chat boost. It can be one of
+/// ```text
+/// ChatBoostSourcePremium
+/// ChatBoostSourceGiftCode
+/// ChatBoostSourceGiveaway
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "source")]
+pub enum ChatBoostSource {
+    #[serde(rename = "premium")]
+    ChatBoostSourcePremium(ChatBoostSourcePremium),
+    #[serde(rename = "gift_code")]
+    ChatBoostSourceGiftCode(ChatBoostSourceGiftCode),
+    #[serde(rename = "giveaway")]
+    ChatBoostSourceGiveaway(ChatBoostSourceGiveaway),
+}
+
 /// This object represents the scope to which bot commands are applied. Currently, the following 7 scopes are supported:
 /// ```
 /// BotCommandScopeDefault
@@ -4344,6 +7037,29 @@ pub enum MenuButton {
     MenuButtonDefault(MenuButtonDefault),
 }
 
+impl MenuButton {
+    /// commands creates a menu button that opens the bot's list of commands.
+    pub fn commands() -> Self {
+        MenuButton::MenuButtonCommands(MenuButtonCommands::new())
+    }
+    /// default_button creates the default menu button, which resets the chat to the default behaviour.
+    pub fn default_button() -> Self {
+        MenuButton::MenuButtonDefault(MenuButtonDefault::new())
+    }
+    /// web_app creates a menu button that launches a Web App.
+    pub fn web_app(text: String, web_app: WebAppInfo) -> Self {
+        MenuButton::MenuButtonWebApp(MenuButtonWebApp::new(text, web_app))
+    }
+    /// kind returns the type of the menu button as sent by the API: “commands”, “web_app” or “default”.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            MenuButton::MenuButtonCommands(_) => "commands",
+            MenuButton::MenuButtonWebApp(_) => "web_app",
+            MenuButton::MenuButtonDefault(_) => "default",
+        }
+    }
+}
+
 /// This object represents the content of a media message to be sent. It should be one of
 /// ```
 /// InputMediaAnimation
@@ -4368,6 +7084,26 @@ pub enum InputMedia {
 }
 
 impl InputMedia {
+    /// animation wraps InputMediaAnimation::new(media).
+    pub fn animation(media: InputFile) -> Self {
+        Self::InputMediaAnimation(InputMediaAnimation::new(media))
+    }
+    /// document wraps InputMediaDocument::new(media).
+    pub fn document(media: InputFile) -> Self {
+        Self::InputMediaDocument(InputMediaDocument::new(media))
+    }
+    /// audio wraps InputMediaAudio::new(media).
+    pub fn audio(media: InputFile) -> Self {
+        Self::InputMediaAudio(InputMediaAudio::new(media))
+    }
+    /// photo wraps InputMediaPhoto::new(media).
+    pub fn photo(media: InputFile) -> Self {
+        Self::InputMediaPhoto(InputMediaPhoto::new(media))
+    }
+    /// video wraps InputMediaVideo::new(media).
+    pub fn video(media: InputFile) -> Self {
+        Self::InputMediaVideo(InputMediaVideo::new(media))
+    }
     /// prepare_input_media_param evaluates a single InputMedia and determines if it
     /// needs to be modified for a successful upload. If it returns nil, then the
     /// value does not need to be included in the params. Otherwise, it will return
@@ -4485,62 +7221,301 @@ impl InputMedia {
             InputMedia::InputMediaDocument(document) => {
                 let media = document.media.clone();
                 if media.need_upload() {
-                    result.push((Self::attach_file_name(idx), media.clone()));
-                }
-                if let Some(thumb) = &document.thumb {
-                    if thumb.need_upload() {
-                        result.push((Self::attach_thumb_file_name(idx), thumb.clone()));
-                    }
+                    result.push((Self::attach_file_name(idx), media.clone()));
+                }
+                if let Some(thumb) = &document.thumb {
+                    if thumb.need_upload() {
+                        result.push((Self::attach_thumb_file_name(idx), thumb.clone()));
+                    }
+                }
+            }
+            InputMedia::InputMediaAudio(audio) => {
+                let media = audio.media.clone();
+                if media.need_upload() {
+                    result.push((Self::attach_file_name(idx), media.clone()));
+                }
+                if let Some(thumb) = &audio.thumb {
+                    if thumb.need_upload() {
+                        result.push((Self::attach_thumb_file_name(idx), thumb.clone()));
+                    }
+                }
+            }
+            InputMedia::InputMediaPhoto(photo) => {
+                if photo.media.need_upload() {
+                    result.push((Self::attach_file_name(idx), photo.media.clone()));
+                }
+            }
+            InputMedia::InputMediaVideo(video) => {
+                let media = video.media.clone();
+                if media.need_upload() {
+                    result.push((Self::attach_file_name(idx), media.clone()));
+                }
+                if let Some(thumb) = &video.thumb {
+                    if thumb.need_upload() {
+                        result.push((Self::attach_thumb_file_name(idx), thumb.clone()));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn attach_file_name(idx: i32) -> String {
+        format!("file-{}", idx)
+    }
+
+    fn attach_thumb_file_name(idx: i32) -> String {
+        format!("file-{}-thumb", idx)
+    }
+
+    fn attach_file(idx: i32) -> InputFile {
+        InputFile::FileAttach(format!("attach://file-{}", idx))
+    }
+
+    fn attach_thumb_file(idx: i32) -> InputFile {
+        InputFile::FileAttach(format!("attach://file-{}-thumb", idx))
+    }
+}
+
+/// The paid media to send is a photo.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputPaidMediaPhoto {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
+    pub media: InputFile,
+}
+impl InputPaidMediaPhoto {
+    pub fn new(media: InputFile) -> Self {
+        Self { media }
+    }
+}
+
+/// The paid media to send is a video.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InputPaidMediaVideo {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
+    pub media: InputFile,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
+    #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFile>,
+    /// Optional. The thumbnail under its name before Bot API 6.6. Only read when deserializing; set thumb to send a thumbnail.
+    #[serde(rename = "thumb", default, skip_serializing)]
+    pub legacy_thumb: Option<InputFile>,
+    /// Optional. Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Optional. Pass True if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+}
+impl InputPaidMediaVideo {
+    pub fn new(media: InputFile) -> Self {
+        Self {
+            media,
+            thumb: None,
+            legacy_thumb: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: None,
+        }
+    }
+}
+
+/// This object describes the paid media to be sent. Currently, it can be one of
+/// ```text
+/// InputPaidMediaPhoto
+/// InputPaidMediaVideo
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum InputPaidMedia {
+    #[serde(rename = "photo")]
+    InputPaidMediaPhoto(InputPaidMediaPhoto),
+    #[serde(rename = "video")]
+    InputPaidMediaVideo(InputPaidMediaVideo),
+}
+
+impl InputPaidMedia {
+    /// photo wraps InputPaidMediaPhoto::new(media).
+    pub fn photo(media: InputFile) -> Self {
+        Self::InputPaidMediaPhoto(InputPaidMediaPhoto::new(media))
+    }
+    /// video wraps InputPaidMediaVideo::new(media).
+    pub fn video(media: InputFile) -> Self {
+        Self::InputPaidMediaVideo(InputPaidMediaVideo::new(media))
+    }
+
+    /// prepare_input_paid_media_param replaces uploadable files with attach:// references,
+    /// using the same "file-%d" naming as InputMedia::prepare_input_media_param.
+    pub fn prepare_input_paid_media_param(&self, idx: i32) -> Self {
+        match self {
+            InputPaidMedia::InputPaidMediaPhoto(photo) => {
+                let mut media = photo.media.clone();
+                if media.need_upload() {
+                    media = InputMedia::attach_file(idx);
                 }
+                Self::InputPaidMediaPhoto(InputPaidMediaPhoto { media })
             }
-            InputMedia::InputMediaAudio(audio) => {
-                let media = audio.media.clone();
+            InputPaidMedia::InputPaidMediaVideo(video) => {
+                let mut media = video.media.clone();
                 if media.need_upload() {
-                    result.push((Self::attach_file_name(idx), media.clone()));
+                    media = InputMedia::attach_file(idx);
                 }
-                if let Some(thumb) = &audio.thumb {
-                    if thumb.need_upload() {
-                        result.push((Self::attach_thumb_file_name(idx), thumb.clone()));
+                let mut thumb: Option<InputFile> = None;
+                if let Some(some_thumb) = &video.thumb {
+                    if some_thumb.need_upload() {
+                        thumb = Some(InputMedia::attach_thumb_file(idx));
                     }
                 }
+                Self::InputPaidMediaVideo(InputPaidMediaVideo {
+                    media,
+                    thumb,
+                    ..video.clone()
+                })
             }
-            InputMedia::InputMediaPhoto(photo) => {
+        }
+    }
+
+    /// prepare_input_paid_media_file returns the files to upload for this item, named
+    /// "file-%d" for the main file and "file-%d-thumb" for the thumbnail.
+    pub fn prepare_input_paid_media_file(&self, idx: i32) -> Vec<(String, InputFile)> {
+        let mut result: Vec<(String, InputFile)> = Vec::new();
+        match self {
+            InputPaidMedia::InputPaidMediaPhoto(photo) => {
                 if photo.media.need_upload() {
-                    result.push((Self::attach_file_name(idx), photo.media.clone()));
+                    result.push((InputMedia::attach_file_name(idx), photo.media.clone()));
                 }
             }
-            InputMedia::InputMediaVideo(video) => {
-                let media = video.media.clone();
-                if media.need_upload() {
-                    result.push((Self::attach_file_name(idx), media.clone()));
+            InputPaidMedia::InputPaidMediaVideo(video) => {
+                if video.media.need_upload() {
+                    result.push((InputMedia::attach_file_name(idx), video.media.clone()));
                 }
                 if let Some(thumb) = &video.thumb {
                     if thumb.need_upload() {
-                        result.push((Self::attach_thumb_file_name(idx), thumb.clone()));
+                        result.push((InputMedia::attach_thumb_file_name(idx), thumb.clone()));
                     }
                 }
             }
         }
         result
     }
+}
 
-    fn attach_file_name(idx: i32) -> String {
-        format!("file-{}", idx)
+/// Describes the paid media added to a message.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the media
+    pub star_count: i64,
+    /// Information about the paid media
+    pub paid_media: Vec<PaidMedia>,
+}
+impl PaidMediaInfo {
+    pub fn new(star_count: i64, paid_media: Vec<PaidMedia>) -> Self {
+        Self {
+            star_count,
+            paid_media,
+        }
     }
+}
 
-    fn attach_thumb_file_name(idx: i32) -> String {
-        format!("file-{}-thumb", idx)
+/// The paid media isn't available before the payment.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaPreview {
+    /// Optional. Media width as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Optional. Media height as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Optional. Duration of the media in seconds as defined by the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+}
+impl PaidMediaPreview {
+    pub fn new() -> Self {
+        Self {
+            width: None,
+            height: None,
+            duration: None,
+        }
     }
+}
 
-    fn attach_file(idx: i32) -> InputFile {
-        InputFile::FileAttach(format!("attach://file-{}", idx))
+/// The paid media is a photo.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaPhoto {
+    /// The photo
+    pub photo: Vec<PhotoSize>,
+}
+impl PaidMediaPhoto {
+    pub fn new(photo: Vec<PhotoSize>) -> Self {
+        Self { photo }
     }
+}
 
-    fn attach_thumb_file(idx: i32) -> InputFile {
-        InputFile::FileAttach(format!("attach://file-{}-thumb", idx))
+/// The paid media is a video.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaidMediaVideo {
+    /// The video
+    pub video: Box<Video>,
+}
+impl PaidMediaVideo {
+    pub fn new(video: Video) -> Self {
+        Self {
+            video: Box::new(video),
+        }
     }
 }
 
+/// This object describes paid media. Currently, it can be one of
+/// ```text
+/// PaidMediaPreview
+/// PaidMediaPhoto
+/// PaidMediaVideo
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum PaidMedia {
+    #[serde(rename = "preview")]
+    PaidMediaPreview(PaidMediaPreview),
+    #[serde(rename = "photo")]
+    PaidMediaPhoto(PaidMediaPhoto),
+    #[serde(rename = "video")]
+    PaidMediaVideo(PaidMediaVideo),
+}
+
+/// This object describes the origin of a message. It can be one of
+/// ```text
+/// MessageOriginUser
+/// MessageOriginHiddenUser
+/// MessageOriginChat
+/// MessageOriginChannel
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum MessageOrigin {
+    #[serde(rename = "user")]
+    MessageOriginUser(MessageOriginUser),
+    #[serde(rename = "hidden_user")]
+    MessageOriginHiddenUser(MessageOriginHiddenUser),
+    #[serde(rename = "chat")]
+    MessageOriginChat(MessageOriginChat),
+    #[serde(rename = "channel")]
+    MessageOriginChannel(MessageOriginChannel),
+}
+
 /// method will return Message or True
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -4549,6 +7524,29 @@ pub enum MayBeMessage {
     Bool(bool),
 }
 
+impl MayBeMessage {
+    /// message returns the edited message, or None if an inline message was edited.
+    pub fn message(self) -> Option<Message> {
+        match self {
+            MayBeMessage::Message(message) => Some(message),
+            MayBeMessage::Bool(_) => None,
+        }
+    }
+    /// is_success reports whether the edit succeeded, either returning the message or True.
+    pub fn is_success(&self) -> bool {
+        matches!(self, MayBeMessage::Message(_) | MayBeMessage::Bool(true))
+    }
+}
+
+/// One of the video chat service messages a Message can carry
+#[derive(Debug, Clone)]
+pub enum VideoChatEvent<'a> {
+    Scheduled(&'a VideoChatScheduled),
+    Started(&'a VideoChatStarted),
+    Ended(&'a VideoChatEnded),
+    ParticipantsInvited(&'a VideoChatParticipantsInvited),
+}
+
 impl Chat {
     pub fn new_private(id: i64) -> Self {
         Self::new(id, ChatType::Private)
@@ -4574,6 +7572,163 @@ impl Chat {
     pub fn is_channel(&self) -> bool {
         matches!(self.type_name, ChatType::Channel)
     }
+    /// chat_type returns the type of the chat.
+    pub fn chat_type(&self) -> &ChatType {
+        &self.type_name
+    }
+}
+
+impl ChatFullInfo {
+    pub fn is_private(&self) -> bool {
+        matches!(self.type_name, ChatType::Private)
+    }
+    pub fn is_group(&self) -> bool {
+        matches!(self.type_name, ChatType::Group)
+    }
+    pub fn is_super_group(&self) -> bool {
+        matches!(self.type_name, ChatType::Supergroup)
+    }
+    pub fn is_channel(&self) -> bool {
+        matches!(self.type_name, ChatType::Channel)
+    }
+    /// chat_type returns the type of the chat.
+    pub fn chat_type(&self) -> &ChatType {
+        &self.type_name
+    }
+}
+
+impl Message {
+    /// sender_boost_count returns the number of boosts the sender has applied to the chat, or 0.
+    pub fn sender_boost_count(&self) -> i64 {
+        self.sender_boost_count.unwrap_or(0)
+    }
+    /// sender_is_bot reports whether the sender in from is a bot; None if from is absent.
+    pub fn sender_is_bot(&self) -> Option<bool> {
+        self.from.as_ref().map(|user| user.is_bot)
+    }
+    /// sender_id returns the id of the user in from, if any.
+    pub fn sender_id(&self) -> Option<i64> {
+        self.from.as_ref().map(|user| user.id)
+    }
+    /// is_anonymous_admin reports whether the message was sent by an anonymous group
+    /// administrator, i.e. on behalf of the chat itself rather than a user. from is not checked,
+    /// as Telegram fills it with a fake sender user for such messages. Channel posts always carry
+    /// the channel as sender_chat, so they are never reported as anonymous admin messages.
+    pub fn is_anonymous_admin(&self) -> bool {
+        (self.chat.is_group() || self.chat.is_super_group())
+            && self
+                .sender_chat
+                .as_ref()
+                .is_some_and(|sender| sender.id == self.chat.id)
+    }
+    /// thread_id returns the forum topic the message belongs to, for routing replies back into it.
+    pub fn thread_id(&self) -> Option<i64> {
+        if self.is_topic_message != Some(true) {
+            return None;
+        }
+        self.message_thread_id
+    }
+    /// forward_origin returns where a forwarded message came from, falling back to the legacy forward_* fields when forward_origin is absent.
+    #[allow(deprecated)]
+    pub fn forward_origin(&self) -> Option<MessageOrigin> {
+        if let Some(origin) = &self.forward_origin {
+            return Some(origin.clone());
+        }
+        let date = self.forward_date?;
+        if let Some(chat) = &self.forward_from_chat {
+            return Some(match self.forward_from_message_id {
+                Some(message_id) => MessageOrigin::MessageOriginChannel(MessageOriginChannel {
+                    date,
+                    chat: chat.clone(),
+                    message_id,
+                    author_signature: self.forward_signature.clone(),
+                }),
+                None => MessageOrigin::MessageOriginChat(MessageOriginChat {
+                    date,
+                    sender_chat: chat.clone(),
+                    author_signature: self.forward_signature.clone(),
+                }),
+            });
+        }
+        if let Some(user) = &self.forward_from {
+            return Some(MessageOrigin::MessageOriginUser(MessageOriginUser::new(
+                date,
+                user.clone(),
+            )));
+        }
+        self.forward_sender_name.as_ref().map(|name| {
+            MessageOrigin::MessageOriginHiddenUser(MessageOriginHiddenUser::new(date, name.clone()))
+        })
+    }
+    /// video_chat_event returns the video chat service message carried by this message, if any.
+    pub fn video_chat_event(&self) -> Option<VideoChatEvent<'_>> {
+        if let Some(scheduled) = &self.video_chat_scheduled {
+            return Some(VideoChatEvent::Scheduled(scheduled));
+        }
+        if let Some(started) = &self.video_chat_started {
+            return Some(VideoChatEvent::Started(started));
+        }
+        if let Some(ended) = &self.video_chat_ended {
+            return Some(VideoChatEvent::Ended(ended));
+        }
+        self.video_chat_participants_invited
+            .as_ref()
+            .map(VideoChatEvent::ParticipantsInvited)
+    }
+    /// reply_text creates a SendMessage replying to this message in the same chat and, if the
+    /// message is in a forum topic, the same topic.
+    pub fn reply_text(&self, text: String) -> crate::methods::SendMessage {
+        let mut request = crate::methods::SendMessage::new(ChatId::IntType(self.chat.id), text);
+        request.message_thread_id = self.thread_id();
+        request.reply_to_message_id = Some(self.message_id);
+        request
+    }
+    /// reply_photo creates a SendPhoto replying to this message in the same chat and forum topic.
+    pub fn reply_photo(&self, photo: InputFile) -> crate::methods::SendPhoto {
+        let mut request = crate::methods::SendPhoto::new(ChatId::IntType(self.chat.id), photo);
+        request.message_thread_id = self.thread_id();
+        request.reply_to_message_id = Some(self.message_id);
+        request
+    }
+    /// reply_document creates a SendDocument replying to this message in the same chat and forum topic.
+    pub fn reply_document(&self, document: InputFile) -> crate::methods::SendDocument {
+        let mut request =
+            crate::methods::SendDocument::new(ChatId::IntType(self.chat.id), document);
+        request.message_thread_id = self.thread_id();
+        request.reply_to_message_id = Some(self.message_id);
+        request
+    }
+    /// forward_to creates a ForwardMessage forwarding this message to chat_id.
+    pub fn forward_to(&self, chat_id: ChatId) -> crate::methods::ForwardMessage {
+        crate::methods::ForwardMessage::new(chat_id, ChatId::IntType(self.chat.id), self.message_id)
+    }
+    /// copy_to creates a CopyMessage copying this message to chat_id, without a link to the original.
+    pub fn copy_to(&self, chat_id: ChatId) -> crate::methods::CopyMessage {
+        crate::methods::CopyMessage::new(chat_id, ChatId::IntType(self.chat.id), self.message_id)
+    }
+    /// custom_emoji_ids returns the unique custom emoji ids used in entities and
+    /// caption_entities, in the order they first appear.
+    pub fn custom_emoji_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = Vec::new();
+        let entities = self.entities.iter().chain(self.caption_entities.iter());
+        for entity in entities.flatten() {
+            if let Some(id) = &entity.custom_emoji_id {
+                if !ids.contains(id) {
+                    ids.push(id.clone());
+                }
+            }
+        }
+        ids
+    }
+    /// resolve_custom_emojis creates a GetCustomEmojiStickers for the custom emojis used in
+    /// the message, or None if it has none.
+    pub fn resolve_custom_emojis(&self) -> Option<crate::methods::GetCustomEmojiStickers> {
+        let ids = self.custom_emoji_ids();
+        if ids.is_empty() {
+            return None;
+        }
+        Some(crate::methods::GetCustomEmojiStickers::new(ids))
+    }
 }
 
 impl MessageEntity {
@@ -4622,6 +7777,184 @@ impl MessageEntity {
     pub fn new_text_mention(offset: i64, length: i64) -> Self {
         Self::new("text_mention".to_string(), offset, length)
     }
+    /// is_code_block reports whether the entity is monowidth text, either a “pre” block or inline “code”.
+    pub fn is_code_block(&self) -> bool {
+        matches!(self.type_name.as_str(), "pre" | "code")
+    }
+    /// code_language returns the programming language of a “pre” block, if one was given.
+    pub fn code_language(&self) -> Option<&str> {
+        if self.type_name != "pre" {
+            return None;
+        }
+        self.language.as_deref()
+    }
+}
+
+/// split_message splits text into chunks of at most limit UTF-16 code units, as sendMessage
+/// accepts at most 4096 per message. Chunks are cut at the last newline before the limit when
+/// it falls in the second half of the chunk, otherwise at whichever of the last newline or space
+/// is nearer the limit, and the separator itself is dropped. Entity offsets are
+/// recomputed per chunk. Formatting entities straddling a boundary are clipped to each chunk;
+/// entities whose text is only meaningful whole (mentions, URLs, bot commands, ...) are dropped.
+pub fn split_message(
+    text: &str,
+    entities: &[MessageEntity],
+    limit: usize,
+) -> Vec<(String, Vec<MessageEntity>)> {
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let limit = limit.max(2);
+    let mut result = Vec::new();
+    let mut start = 0;
+    while start < units.len() {
+        let (end, next) = if units.len() - start <= limit {
+            (units.len(), units.len())
+        } else {
+            let window = &units[start..start + limit + 1];
+            let newline = window.iter().rposition(|unit| *unit == '\n' as u16);
+            let space = window.iter().rposition(|unit| *unit == ' ' as u16);
+            // an early newline would leave a tiny chunk, so it only wins in the back half
+            let separator = match (newline, space) {
+                (Some(newline), _) if newline >= limit / 2 => Some(newline),
+                (newline, space) => newline.max(space),
+            }
+            .filter(|position| *position > 0);
+            match separator {
+                Some(position) => (start + position, start + position + 1),
+                None => {
+                    let mut end = start + limit;
+                    // never cut a surrogate pair in half
+                    if (0xD800..0xDC00).contains(&units[end - 1]) {
+                        end -= 1;
+                    }
+                    (end, end)
+                }
+            }
+        };
+        let chunk_entities = entities
+            .iter()
+            .filter_map(|entity| {
+                let entity_start = entity.offset.max(0) as usize;
+                let entity_end = entity_start + entity.length.max(0) as usize;
+                let clipped_start = entity_start.max(start);
+                let clipped_end = entity_end.min(end);
+                if clipped_start >= clipped_end {
+                    return None;
+                }
+                if (clipped_start, clipped_end) != (entity_start, entity_end)
+                    && !entity.can_be_clipped()
+                {
+                    return None;
+                }
+                Some(MessageEntity {
+                    offset: (clipped_start - start) as i64,
+                    length: (clipped_end - clipped_start) as i64,
+                    ..entity.clone()
+                })
+            })
+            .collect();
+        result.push((String::from_utf16_lossy(&units[start..end]), chunk_entities));
+        start = next;
+    }
+    result
+}
+
+impl MessageEntity {
+    /// can_be_clipped reports whether a part of the entity still makes sense on its own.
+    fn can_be_clipped(&self) -> bool {
+        matches!(
+            self.type_name.as_str(),
+            "bold"
+                | "italic"
+                | "underline"
+                | "strikethrough"
+                | "spoiler"
+                | "code"
+                | "pre"
+                | "text_link"
+                | "text_mention"
+        )
+    }
+}
+
+/// sort_entities orders entities the way they have to be rendered: by offset, and for entities
+/// starting at the same offset, the longest (outermost) first.
+pub fn sort_entities(entities: &mut [MessageEntity]) {
+    entities.sort_by(|a, b| a.offset.cmp(&b.offset).then(b.length.cmp(&a.length)));
+}
+
+/// entities_overlap reports whether the two entities cover at least one common UTF-16 code unit.
+pub fn entities_overlap(a: &MessageEntity, b: &MessageEntity) -> bool {
+    a.offset < b.offset + b.length && b.offset < a.offset + a.length
+}
+
+/// EntityBuilder builds a text together with its entities, computing UTF-16 offsets as spans are
+/// appended. The result can be sent with entities or caption_entities instead of a parse_mode, so
+/// no escaping is needed.
+#[derive(Debug, Clone, Default)]
+pub struct EntityBuilder {
+    text: String,
+    entities: Vec<MessageEntity>,
+    offset: i64,
+}
+
+impl EntityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// text appends plain text.
+    pub fn text(mut self, text: &str) -> Self {
+        self.push(text);
+        self
+    }
+    /// bold appends text wrapped in a “bold” entity.
+    pub fn bold(self, text: &str) -> Self {
+        self.span(text, MessageEntity::new_bold)
+    }
+    /// italic appends text wrapped in an “italic” entity.
+    pub fn italic(self, text: &str) -> Self {
+        self.span(text, MessageEntity::new_italic)
+    }
+    /// code appends text wrapped in a “code” entity.
+    pub fn code(self, text: &str) -> Self {
+        self.span(text, MessageEntity::new_code)
+    }
+    /// text_link appends text that opens url when clicked.
+    pub fn text_link(self, text: &str, url: &str) -> Self {
+        self.span(text, |offset, length| MessageEntity {
+            url: Some(url.to_string()),
+            ..MessageEntity::new_text_link(offset, length)
+        })
+    }
+    /// text_mention appends text that mentions a user without a username.
+    pub fn text_mention(self, text: &str, user: User) -> Self {
+        self.span(text, |offset, length| MessageEntity {
+            user: Some(user),
+            ..MessageEntity::new_text_mention(offset, length)
+        })
+    }
+    /// build returns the text and its entities.
+    pub fn build(self) -> (String, Vec<MessageEntity>) {
+        (self.text, self.entities)
+    }
+
+    fn push(&mut self, text: &str) -> i64 {
+        let length = text.encode_utf16().count() as i64;
+        self.text.push_str(text);
+        self.offset += length;
+        length
+    }
+
+    fn span<F>(mut self, text: &str, entity: F) -> Self
+    where
+        F: FnOnce(i64, i64) -> MessageEntity,
+    {
+        let offset = self.offset;
+        let length = self.push(text);
+        if length > 0 {
+            self.entities.push(entity(offset, length));
+        }
+        self
+    }
 }
 
 impl Sticker {
@@ -4679,292 +8012,175 @@ impl Sticker {
             is_video,
         )
     }
+    /// is_premium reports whether the sticker is a premium regular sticker with a premium animation.
+    pub fn is_premium(&self) -> bool {
+        self.premium_animation.is_some()
+    }
 }
 
 impl PassportElementErrorDataField {
-    pub fn new_personal_details(
-        source: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
+    pub fn new_personal_details(field_name: String, data_hash: String, message: String) -> Self {
         Self::new(
-            source,
             "personal_details".to_string(),
             field_name,
             data_hash,
             message,
         )
     }
-    pub fn new_passport(
-        source: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "passport".to_string(),
-            field_name,
-            data_hash,
-            message,
-        )
+    pub fn new_passport(field_name: String, data_hash: String, message: String) -> Self {
+        Self::new("passport".to_string(), field_name, data_hash, message)
     }
-    pub fn new_driver_license(
-        source: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "driver_license".to_string(),
-            field_name,
-            data_hash,
-            message,
-        )
+    pub fn new_driver_license(field_name: String, data_hash: String, message: String) -> Self {
+        Self::new("driver_license".to_string(), field_name, data_hash, message)
     }
-    pub fn new_identity_card(
-        source: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "identity_card".to_string(),
-            field_name,
-            data_hash,
-            message,
-        )
+    pub fn new_identity_card(field_name: String, data_hash: String, message: String) -> Self {
+        Self::new("identity_card".to_string(), field_name, data_hash, message)
     }
-    pub fn new_internal_passport(
-        source: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
+    pub fn new_internal_passport(field_name: String, data_hash: String, message: String) -> Self {
         Self::new(
-            source,
             "internal_passport".to_string(),
             field_name,
             data_hash,
             message,
         )
     }
-    pub fn new_address(
-        source: String,
-        field_name: String,
-        data_hash: String,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "address".to_string(),
-            field_name,
-            data_hash,
-            message,
-        )
+    pub fn new_address(field_name: String, data_hash: String, message: String) -> Self {
+        Self::new("address".to_string(), field_name, data_hash, message)
     }
 }
 
 impl PassportElementErrorFrontSide {
-    pub fn new_passport(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "passport".to_string(), file_hash, message)
+    pub fn new_passport(file_hash: String, message: String) -> Self {
+        Self::new("passport".to_string(), file_hash, message)
     }
-    pub fn new_driver_license(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "driver_license".to_string(), file_hash, message)
+    pub fn new_driver_license(file_hash: String, message: String) -> Self {
+        Self::new("driver_license".to_string(), file_hash, message)
     }
-    pub fn new_identity_card(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "identity_card".to_string(), file_hash, message)
+    pub fn new_identity_card(file_hash: String, message: String) -> Self {
+        Self::new("identity_card".to_string(), file_hash, message)
     }
-    pub fn new_internal_passport(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "internal_passport".to_string(), file_hash, message)
+    pub fn new_internal_passport(file_hash: String, message: String) -> Self {
+        Self::new("internal_passport".to_string(), file_hash, message)
     }
 }
 
 impl PassportElementErrorReverseSide {
-    pub fn new_driver_license(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "driver_license".to_string(), file_hash, message)
+    pub fn new_driver_license(file_hash: String, message: String) -> Self {
+        Self::new("driver_license".to_string(), file_hash, message)
     }
-    pub fn new_identity_card(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "identity_card".to_string(), file_hash, message)
+    pub fn new_identity_card(file_hash: String, message: String) -> Self {
+        Self::new("identity_card".to_string(), file_hash, message)
     }
 }
 
 impl PassportElementErrorSelfie {
-    pub fn new_passport(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "passport".to_string(), file_hash, message)
+    pub fn new_passport(file_hash: String, message: String) -> Self {
+        Self::new("passport".to_string(), file_hash, message)
     }
-    pub fn new_driver_license(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "driver_license".to_string(), file_hash, message)
+    pub fn new_driver_license(file_hash: String, message: String) -> Self {
+        Self::new("driver_license".to_string(), file_hash, message)
     }
-    pub fn new_identity_card(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "identity_card".to_string(), file_hash, message)
+    pub fn new_identity_card(file_hash: String, message: String) -> Self {
+        Self::new("identity_card".to_string(), file_hash, message)
     }
-    pub fn new_internal_passport(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "internal_passport".to_string(), file_hash, message)
+    pub fn new_internal_passport(file_hash: String, message: String) -> Self {
+        Self::new("internal_passport".to_string(), file_hash, message)
     }
 }
 
 impl PassportElementErrorFile {
-    pub fn new_utility_bill(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "utility_bill".to_string(), file_hash, message)
+    pub fn new_utility_bill(file_hash: String, message: String) -> Self {
+        Self::new("utility_bill".to_string(), file_hash, message)
     }
-    pub fn new_bank_statement(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "bank_statement".to_string(), file_hash, message)
+    pub fn new_bank_statement(file_hash: String, message: String) -> Self {
+        Self::new("bank_statement".to_string(), file_hash, message)
     }
-    pub fn new_rental_agreement(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "rental_agreement".to_string(), file_hash, message)
+    pub fn new_rental_agreement(file_hash: String, message: String) -> Self {
+        Self::new("rental_agreement".to_string(), file_hash, message)
     }
-    pub fn new_passport_registration(source: String, file_hash: String, message: String) -> Self {
-        Self::new(
-            source,
-            "passport_registration".to_string(),
-            file_hash,
-            message,
-        )
+    pub fn new_passport_registration(file_hash: String, message: String) -> Self {
+        Self::new("passport_registration".to_string(), file_hash, message)
     }
-    pub fn new_temporary_registration(source: String, file_hash: String, message: String) -> Self {
-        Self::new(
-            source,
-            "temporary_registration".to_string(),
-            file_hash,
-            message,
-        )
+    pub fn new_temporary_registration(file_hash: String, message: String) -> Self {
+        Self::new("temporary_registration".to_string(), file_hash, message)
     }
 }
 
 impl PassportElementErrorFiles {
-    pub fn new_utility_bill(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "utility_bill".to_string(), file_hashes, message)
+    pub fn new_utility_bill(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("utility_bill".to_string(), file_hashes, message)
     }
-    pub fn new_bank_statement(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "bank_statement".to_string(), file_hashes, message)
+    pub fn new_bank_statement(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("bank_statement".to_string(), file_hashes, message)
     }
-    pub fn new_rental_agreement(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "rental_agreement".to_string(), file_hashes, message)
+    pub fn new_rental_agreement(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("rental_agreement".to_string(), file_hashes, message)
     }
-    pub fn new_passport_registration(
-        source: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "passport_registration".to_string(),
-            file_hashes,
-            message,
-        )
+    pub fn new_passport_registration(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("passport_registration".to_string(), file_hashes, message)
     }
-    pub fn new_temporary_registration(
-        source: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "temporary_registration".to_string(),
-            file_hashes,
-            message,
-        )
+    pub fn new_temporary_registration(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("temporary_registration".to_string(), file_hashes, message)
     }
 }
 
 impl PassportElementErrorTranslationFile {
-    pub fn new_passport(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "passport".to_string(), file_hash, message)
+    pub fn new_passport(file_hash: String, message: String) -> Self {
+        Self::new("passport".to_string(), file_hash, message)
     }
-    pub fn new_driver_license(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "driver_license".to_string(), file_hash, message)
+    pub fn new_driver_license(file_hash: String, message: String) -> Self {
+        Self::new("driver_license".to_string(), file_hash, message)
     }
-    pub fn new_identity_card(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "identity_card".to_string(), file_hash, message)
+    pub fn new_identity_card(file_hash: String, message: String) -> Self {
+        Self::new("identity_card".to_string(), file_hash, message)
     }
-    pub fn new_internal_passport(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "internal_passport".to_string(), file_hash, message)
+    pub fn new_internal_passport(file_hash: String, message: String) -> Self {
+        Self::new("internal_passport".to_string(), file_hash, message)
     }
-    pub fn new_utility_bill(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "utility_bill".to_string(), file_hash, message)
+    pub fn new_utility_bill(file_hash: String, message: String) -> Self {
+        Self::new("utility_bill".to_string(), file_hash, message)
     }
-    pub fn new_bank_statement(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "bank_statement".to_string(), file_hash, message)
+    pub fn new_bank_statement(file_hash: String, message: String) -> Self {
+        Self::new("bank_statement".to_string(), file_hash, message)
     }
-    pub fn new_rental_agreement(source: String, file_hash: String, message: String) -> Self {
-        Self::new(source, "rental_agreement".to_string(), file_hash, message)
+    pub fn new_rental_agreement(file_hash: String, message: String) -> Self {
+        Self::new("rental_agreement".to_string(), file_hash, message)
     }
-    pub fn new_passport_registration(source: String, file_hash: String, message: String) -> Self {
-        Self::new(
-            source,
-            "passport_registration".to_string(),
-            file_hash,
-            message,
-        )
+    pub fn new_passport_registration(file_hash: String, message: String) -> Self {
+        Self::new("passport_registration".to_string(), file_hash, message)
     }
-    pub fn new_temporary_registration(source: String, file_hash: String, message: String) -> Self {
-        Self::new(
-            source,
-            "temporary_registration".to_string(),
-            file_hash,
-            message,
-        )
+    pub fn new_temporary_registration(file_hash: String, message: String) -> Self {
+        Self::new("temporary_registration".to_string(), file_hash, message)
     }
 }
 
 impl PassportElementErrorTranslationFiles {
-    pub fn new_passport(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "passport".to_string(), file_hashes, message)
+    pub fn new_passport(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("passport".to_string(), file_hashes, message)
     }
-    pub fn new_driver_license(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "driver_license".to_string(), file_hashes, message)
+    pub fn new_driver_license(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("driver_license".to_string(), file_hashes, message)
     }
-    pub fn new_identity_card(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "identity_card".to_string(), file_hashes, message)
+    pub fn new_identity_card(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("identity_card".to_string(), file_hashes, message)
     }
-    pub fn new_internal_passport(
-        source: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "internal_passport".to_string(),
-            file_hashes,
-            message,
-        )
+    pub fn new_internal_passport(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("internal_passport".to_string(), file_hashes, message)
     }
-    pub fn new_utility_bill(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "utility_bill".to_string(), file_hashes, message)
+    pub fn new_utility_bill(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("utility_bill".to_string(), file_hashes, message)
     }
-    pub fn new_bank_statement(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "bank_statement".to_string(), file_hashes, message)
+    pub fn new_bank_statement(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("bank_statement".to_string(), file_hashes, message)
     }
-    pub fn new_rental_agreement(source: String, file_hashes: Vec<String>, message: String) -> Self {
-        Self::new(source, "rental_agreement".to_string(), file_hashes, message)
+    pub fn new_rental_agreement(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("rental_agreement".to_string(), file_hashes, message)
     }
-    pub fn new_passport_registration(
-        source: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "passport_registration".to_string(),
-            file_hashes,
-            message,
-        )
+    pub fn new_passport_registration(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("passport_registration".to_string(), file_hashes, message)
     }
-    pub fn new_temporary_registration(
-        source: String,
-        file_hashes: Vec<String>,
-        message: String,
-    ) -> Self {
-        Self::new(
-            source,
-            "temporary_registration".to_string(),
-            file_hashes,
-            message,
-        )
+    pub fn new_temporary_registration(file_hashes: Vec<String>, message: String) -> Self {
+        Self::new("temporary_registration".to_string(), file_hashes, message)
     }
 }
 
@@ -4976,14 +8192,44 @@ impl PassportElementErrorTranslationFiles {
 /// InputContactMessageContent
 /// InputInvoiceMessageContent
 /// ```
+///
+/// The content has no type field, so deserialization tries the variants in declaration order
+/// and picks the first whose required fields are all present. The variants are ordered from
+/// most to least specific: invoice, venue (a location with title and address), location,
+/// contact and text, so that a venue is never read back as a plain location.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum InputMessageContent {
-    InputTextMessageContent(InputTextMessageContent),
-    InputLocationMessageContent(InputLocationMessageContent),
+    InputInvoiceMessageContent(InputInvoiceMessageContent),
     InputVenueMessageContent(InputVenueMessageContent),
+    InputLocationMessageContent(InputLocationMessageContent),
     InputContactMessageContent(InputContactMessageContent),
-    InputInvoiceMessageContent(InputInvoiceMessageContent),
+    InputTextMessageContent(InputTextMessageContent),
+}
+
+/// The kind of an InputMessageContent, see InputMessageContent::content_kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMessageContentKind {
+    Text,
+    Location,
+    Venue,
+    Contact,
+    Invoice,
+}
+
+impl InputMessageContent {
+    /// content_kind returns which kind of content this is.
+    pub fn content_kind(&self) -> InputMessageContentKind {
+        match self {
+            InputMessageContent::InputTextMessageContent(_) => InputMessageContentKind::Text,
+            InputMessageContent::InputLocationMessageContent(_) => {
+                InputMessageContentKind::Location
+            }
+            InputMessageContent::InputVenueMessageContent(_) => InputMessageContentKind::Venue,
+            InputMessageContent::InputContactMessageContent(_) => InputMessageContentKind::Contact,
+            InputMessageContent::InputInvoiceMessageContent(_) => InputMessageContentKind::Invoice,
+        }
+    }
 }
 
 /// This object represents an error in the Telegram Passport element which was submitted that should be resolved by the user. It should be one of:
@@ -5078,3 +8324,44 @@ pub enum InlineQueryResult {
     #[serde(rename = "voice")]
     InlineQueryResultVoice(InlineQueryResultVoice),
 }
+
+/// match_inline_query_result evaluates body with result bound to the variant's struct, as every
+/// variant has the same id and reply_markup fields.
+macro_rules! match_inline_query_result {
+    ($value:expr, $result:ident => $body:expr) => {
+        match $value {
+            InlineQueryResult::InlineQueryResultCachedAudio($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedDocument($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedGif($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedMpeg4Gif($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedPhoto($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedSticker($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedVideo($result) => $body,
+            InlineQueryResult::InlineQueryResultCachedVoice($result) => $body,
+            InlineQueryResult::InlineQueryResultArticle($result) => $body,
+            InlineQueryResult::InlineQueryResultAudio($result) => $body,
+            InlineQueryResult::InlineQueryResultContact($result) => $body,
+            InlineQueryResult::InlineQueryResultGame($result) => $body,
+            InlineQueryResult::InlineQueryResultDocument($result) => $body,
+            InlineQueryResult::InlineQueryResultGif($result) => $body,
+            InlineQueryResult::InlineQueryResultLocation($result) => $body,
+            InlineQueryResult::InlineQueryResultMpeg4Gif($result) => $body,
+            InlineQueryResult::InlineQueryResultPhoto($result) => $body,
+            InlineQueryResult::InlineQueryResultVenue($result) => $body,
+            InlineQueryResult::InlineQueryResultVideo($result) => $body,
+            InlineQueryResult::InlineQueryResultVoice($result) => $body,
+        }
+    };
+}
+
+impl InlineQueryResult {
+    /// id returns the unique identifier of the result, whichever variant it is.
+    pub fn id(&self) -> &str {
+        match_inline_query_result!(self, result => &result.id)
+    }
+    /// with_reply_markup sets the inline keyboard attached to the sent message and returns the result.
+    pub fn with_reply_markup(mut self, markup: InlineKeyboardMarkup) -> Self {
+        match_inline_query_result!(&mut self, result => result.reply_markup = Some(markup));
+        self
+    }
+}
@@ -0,0 +1,22 @@
+use telegram_bot_api::methods;
+
+#[test]
+fn game_url_leaves_text_unset() {
+    let answer = methods::AnswerCallbackQuery::game_url(
+        String::from("42"),
+        String::from("https://example.com/game"),
+    );
+    assert_eq!(answer.url.as_deref(), Some("https://example.com/game"));
+    assert!(answer.text.is_none());
+    assert!(answer.show_alert.is_none());
+}
+
+#[test]
+fn alert_and_toast() {
+    let alert = methods::AnswerCallbackQuery::alert(String::from("42"), String::from("done"));
+    assert_eq!(alert.show_alert, Some(true));
+    assert!(alert.url.is_none());
+    let toast = methods::AnswerCallbackQuery::toast(String::from("42"), String::from("done"));
+    assert_eq!(toast.text.as_deref(), Some("done"));
+    assert!(toast.show_alert.is_none());
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "blocking")]
+
+use telegram_bot_api::types;
+
+#[test]
+fn data_blocking_reads_local_file() {
+    let path = std::env::temp_dir().join(format!("data_blocking_{}.txt", std::process::id()));
+    std::fs::write(&path, b"hello").unwrap();
+    let file = types::InputFile::FilePath(path.to_string_lossy().into_owned());
+    let part = file.data_blocking();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(part, Ok(types::InputFileBlockingResult::Part(_))));
+
+    let missing = types::InputFile::FilePath(path.to_string_lossy().into_owned());
+    assert!(missing.data_blocking().is_err());
+
+    let file = types::InputFile::FileID(String::from("file-id"));
+    assert!(matches!(
+        file.data_blocking(),
+        Ok(types::InputFileBlockingResult::Text(id)) if id == "file-id"
+    ));
+}
@@ -0,0 +1,33 @@
+use telegram_bot_api::types::ChatId;
+
+#[test]
+fn parse_supergroup_id() {
+    let id = ChatId::parse("-1001234567890");
+    assert_eq!(id.as_i64(), Some(-1001234567890));
+    assert_eq!(id.as_username(), None);
+}
+
+#[test]
+fn parse_username() {
+    let id = ChatId::parse("@channel");
+    assert_eq!(id.as_username(), Some("@channel"));
+    assert_eq!(id.as_i64(), None);
+}
+
+#[test]
+fn parse_user_id() {
+    assert!(matches!(ChatId::parse("12345"), ChatId::IntType(12345)));
+}
+
+#[test]
+fn username_and_numeric_targets() {
+    use telegram_bot_api::methods::{BanChatMember, GetChat, Params};
+
+    let request = GetChat::new(ChatId::StringType(String::from("@public")));
+    assert_eq!(request.params().unwrap()["chat_id"], "@public");
+
+    let request = BanChatMember::new(ChatId::StringType(String::from("@public")), 42);
+    let params = request.params().unwrap();
+    assert_eq!(params["chat_id"], "@public");
+    assert_eq!(params["user_id"], 42);
+}
@@ -0,0 +1,165 @@
+use serde_json::json;
+use telegram_bot_api::types;
+
+fn updated(old: serde_json::Value, new: serde_json::Value) -> types::ChatMemberUpdated {
+    let bot = json!({"id": 42, "is_bot": true, "first_name": "Bot"});
+    let mut old = old;
+    let mut new = new;
+    old["user"] = bot.clone();
+    new["user"] = bot.clone();
+    serde_json::from_value(json!({
+        "chat": {"id": -100, "title": "Group", "type": "supergroup"},
+        "from": {"id": 1, "is_bot": false, "first_name": "Alice"},
+        "date": 1666000000,
+        "old_chat_member": old,
+        "new_chat_member": new,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn added_from_left() {
+    let change = updated(json!({"status": "left"}), json!({"status": "member"}));
+    assert_eq!(change.new_chat_member.status(), "member");
+    assert!(change.was_added());
+    assert!(!change.was_removed());
+}
+
+#[test]
+fn added_as_administrator_after_ban() {
+    let change = updated(
+        json!({"status": "kicked", "until_date": 0}),
+        json!({
+            "status": "administrator",
+            "can_be_edited": false,
+            "is_anonymous": false,
+            "can_manage_chat": true,
+            "can_delete_messages": true,
+            "can_manage_video_chats": true,
+            "can_restrict_members": true,
+            "can_promote_members": false,
+            "can_change_info": true,
+            "can_invite_users": true
+        }),
+    );
+    assert!(change.was_added());
+}
+
+#[test]
+fn removed_by_leaving_or_ban() {
+    let left = updated(json!({"status": "member"}), json!({"status": "left"}));
+    assert!(left.was_removed());
+    assert!(!left.was_added());
+    let banned = updated(
+        json!({"status": "member"}),
+        json!({"status": "kicked", "until_date": 0}),
+    );
+    assert!(banned.was_removed());
+}
+
+#[test]
+fn status_change_within_chat() {
+    let change = updated(
+        json!({"status": "left"}),
+        json!({"status": "kicked", "until_date": 0}),
+    );
+    assert!(!change.was_added());
+    assert!(!change.was_removed());
+}
+
+#[test]
+fn promotion_summary() {
+    let change: types::ChatMemberUpdated = serde_json::from_value(json!({
+        "chat": {"id": -100, "title": "Group", "type": "supergroup"},
+        "from": {"id": 456, "is_bot": false, "first_name": "Owner"},
+        "date": 1666000000,
+        "old_chat_member": {
+            "status": "member",
+            "user": {"id": 123, "is_bot": false, "first_name": "Alice"}
+        },
+        "new_chat_member": {
+            "status": "administrator",
+            "user": {"id": 123, "is_bot": false, "first_name": "Alice"},
+            "can_be_edited": true,
+            "is_anonymous": false,
+            "can_manage_chat": true,
+            "can_delete_messages": true,
+            "can_manage_video_chats": true,
+            "can_restrict_members": true,
+            "can_promote_members": false,
+            "can_change_info": true,
+            "can_invite_users": true
+        }
+    }))
+    .unwrap();
+    assert_eq!(change.affected_user().id, 123);
+    assert_eq!(
+        change.summary(),
+        "user 123 (Alice): member → administrator in chat -100 by 456"
+    );
+}
+
+#[test]
+fn permission_presets() {
+    let granted = types::ChatPermissions::all_granted();
+    assert_eq!(granted.can_send_messages, Some(true));
+    assert_eq!(granted.can_pin_messages, Some(true));
+    let denied = types::ChatPermissions::all_denied();
+    assert_eq!(denied.can_send_photos, Some(false));
+    assert_eq!(denied.can_change_info, Some(false));
+
+    let mut read_only = types::ChatPermissions::read_only();
+    assert_eq!(read_only.can_send_messages, Some(false));
+    assert_eq!(read_only.can_send_polls, Some(false));
+    assert_eq!(read_only.can_change_info, None);
+    read_only.can_change_info = Some(true);
+    let serialized = serde_json::to_value(&read_only).unwrap();
+    assert_eq!(serialized["can_send_messages"], false);
+    assert_eq!(serialized["can_change_info"], true);
+    assert!(serialized.get("can_invite_users").is_none());
+}
+
+#[test]
+fn administrator_rights_presets() {
+    let group = types::ChatAdministratorRights::full_for_group();
+    assert!(group.can_restrict_members && group.can_promote_members);
+    assert!(!group.is_anonymous);
+    assert_eq!(group.can_pin_messages, Some(true));
+    assert_eq!(group.can_post_messages, None);
+
+    let channel = types::ChatAdministratorRights::full_for_channel();
+    assert_eq!(channel.can_post_messages, Some(true));
+    assert_eq!(channel.can_edit_messages, Some(true));
+    assert_eq!(channel.can_pin_messages, None);
+}
+
+#[test]
+fn copy_administrator_rights() {
+    use telegram_bot_api::methods::{self, Params};
+
+    let bob: types::ChatMemberAdministrator = serde_json::from_value(json!({
+        "user": {"id": 2, "is_bot": false, "first_name": "Bob"},
+        "can_be_edited": true,
+        "is_anonymous": false,
+        "can_manage_chat": true,
+        "can_delete_messages": true,
+        "can_manage_video_chats": false,
+        "can_restrict_members": true,
+        "can_promote_members": false,
+        "can_change_info": false,
+        "can_invite_users": true,
+        "can_pin_messages": true
+    }))
+    .unwrap();
+    let rights = types::ChatAdministratorRights::from(bob);
+    assert!(rights.can_restrict_members);
+    assert!(!rights.can_promote_members);
+    assert_eq!(rights.can_pin_messages, Some(true));
+
+    let request = methods::PromoteChatMember::with_rights(types::ChatId::IntType(-100), 3, &rights);
+    let params = request.params().unwrap();
+    assert_eq!(params["user_id"], 3);
+    assert_eq!(params["can_restrict_members"], true);
+    assert_eq!(params["can_promote_members"], false);
+    assert!(!params.contains_key("can_post_messages"));
+}
@@ -0,0 +1,35 @@
+use telegram_bot_api::types;
+
+fn dice(emoji: &str, value: i64) -> types::Dice {
+    types::Dice::new(emoji.to_string(), value)
+}
+
+#[test]
+fn is_max_per_emoji() {
+    for (emoji, max) in [
+        ("🎲", 6),
+        ("🎯", 6),
+        ("🎳", 6),
+        ("🏀", 5),
+        ("⚽", 5),
+        ("🎰", 64),
+    ] {
+        assert_eq!(dice(emoji, max).max_value(), Some(max), "{}", emoji);
+        assert!(dice(emoji, max).is_max(), "{}", emoji);
+        assert!(!dice(emoji, max - 1).is_max(), "{}", emoji);
+    }
+}
+
+#[test]
+fn unknown_emoji_has_no_max() {
+    let unknown = dice("🪀", 1);
+    assert_eq!(unknown.max_value(), None);
+    assert!(!unknown.is_max());
+}
+
+#[test]
+fn slot_machine_jackpot() {
+    assert!(dice("🎰", 64).is_jackpot());
+    assert!(!dice("🎰", 1).is_jackpot());
+    assert!(!dice("🎲", 64).is_jackpot());
+}
@@ -0,0 +1,122 @@
+use telegram_bot_api::types;
+
+#[test]
+fn sort_longest_first_at_same_offset() {
+    let mut entities = vec![
+        types::MessageEntity::new_italic(4, 2),
+        types::MessageEntity::new_bold(4, 10),
+        types::MessageEntity::new_code(0, 3),
+        types::MessageEntity::new_underline(4, 5),
+    ];
+    types::sort_entities(&mut entities);
+    let order: Vec<(i64, i64)> = entities.iter().map(|e| (e.offset, e.length)).collect();
+    assert_eq!(order, vec![(0, 3), (4, 10), (4, 5), (4, 2)]);
+}
+
+#[test]
+fn overlap() {
+    let bold = types::MessageEntity::new_bold(0, 5);
+    assert!(types::entities_overlap(
+        &bold,
+        &types::MessageEntity::new_italic(4, 3)
+    ));
+    assert!(!types::entities_overlap(
+        &bold,
+        &types::MessageEntity::new_italic(5, 3)
+    ));
+}
+
+#[test]
+fn entity_builder_offsets() {
+    let (text, entities) = types::EntityBuilder::new()
+        .text("Hello ")
+        .bold("World")
+        .build();
+    assert_eq!(text, "Hello World");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].type_name, "bold");
+    assert_eq!((entities[0].offset, entities[0].length), (6, 5));
+
+    // 🎉 is a surrogate pair: two UTF-16 code units
+    let (text, entities) = types::EntityBuilder::new()
+        .text("🎉 Hello ")
+        .bold("World")
+        .text(" ")
+        .text_link("docs", "https://core.telegram.org/bots/api")
+        .build();
+    assert_eq!(text, "🎉 Hello World docs");
+    assert_eq!((entities[0].offset, entities[0].length), (9, 5));
+    assert_eq!(entities[1].type_name, "text_link");
+    assert_eq!((entities[1].offset, entities[1].length), (15, 4));
+    assert_eq!(
+        entities[1].url.as_deref(),
+        Some("https://core.telegram.org/bots/api")
+    );
+}
+
+#[test]
+fn split_message_at_space_with_bold_span() {
+    let text = format!("{} {}", "x".repeat(3000), "y".repeat(1999));
+    assert_eq!(text.encode_utf16().count(), 5000);
+    // a url can't be clipped, so it is dropped from both chunks
+    let url = types::MessageEntity::new(String::from("url"), 2995, 10);
+    let entities = vec![types::MessageEntity::new_bold(2990, 20), url];
+
+    let chunks = types::split_message(&text, &entities, 4096);
+    assert_eq!(chunks.len(), 2);
+
+    let (first, first_entities) = &chunks[0];
+    assert_eq!(first.encode_utf16().count(), 3000);
+    assert_eq!(first_entities.len(), 1);
+    assert_eq!(first_entities[0].type_name, "bold");
+    assert_eq!(
+        (first_entities[0].offset, first_entities[0].length),
+        (2990, 10)
+    );
+
+    let (second, second_entities) = &chunks[1];
+    assert_eq!(second, &"y".repeat(1999));
+    assert_eq!(second_entities.len(), 1);
+    assert_eq!(
+        (second_entities[0].offset, second_entities[0].length),
+        (0, 9)
+    );
+}
+
+#[test]
+fn split_message_keeps_surrogate_pairs_whole() {
+    let text = "😀".repeat(5);
+    let chunks = types::split_message(&text, &[], 5);
+    let texts: Vec<&str> = chunks.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(texts, vec!["😀😀", "😀😀", "😀"]);
+}
+
+#[test]
+fn split_message_skips_early_newline() {
+    let text = format!("Report\n{}", "lorem ".repeat(30));
+    let chunks = types::split_message(text.trim_end(), &[], 100);
+    let texts: Vec<&str> = chunks.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(texts.len(), 2);
+    assert!(texts[0].starts_with("Report\nlorem"), "{}", texts[0]);
+    assert_eq!(texts[0].encode_utf16().count(), 96);
+
+    let text = format!("{}\n{}", "a".repeat(70), "b ".repeat(20));
+    let chunks = types::split_message(&text, &[], 100);
+    assert_eq!(chunks[0].0, "a".repeat(70));
+}
+
+#[test]
+fn pre_entity_code_language() {
+    let mut pre = types::MessageEntity::new(String::from("pre"), 0, 12);
+    assert!(pre.is_code_block());
+    assert_eq!(pre.code_language(), None);
+    pre.language = Some(String::from("rust"));
+    assert_eq!(pre.code_language(), Some("rust"));
+
+    let mut code = types::MessageEntity::new(String::from("code"), 0, 4);
+    code.language = Some(String::from("rust"));
+    assert!(code.is_code_block());
+    assert_eq!(code.code_language(), None);
+
+    assert!(!types::MessageEntity::new_bold(0, 4).is_code_block());
+}
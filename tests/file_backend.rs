@@ -0,0 +1,65 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+use telegram_bot_api::types;
+
+/// MockBackend records the file names it was asked to build parts for.
+#[derive(Default)]
+struct MockBackend {
+    names: RefCell<Vec<String>>,
+}
+
+impl types::FileBackend for MockBackend {
+    type Part = usize;
+    fn bytes(&self, file_name: &str, bytes: Vec<u8>) -> Self::Part {
+        self.names.borrow_mut().push(file_name.to_string());
+        bytes.len()
+    }
+    async fn path(&self, path: &str) -> Result<Self::Part, Box<dyn std::error::Error>> {
+        self.names.borrow_mut().push(path.to_string());
+        Ok(0)
+    }
+}
+
+/// block_on drives a future that never waits, as the mock backend does no I/O.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("mock backend future is not ready"),
+    }
+}
+
+#[test]
+fn mock_backend_records_file_names() {
+    let backend = MockBackend::default();
+    let files = [
+        types::InputFile::FileBytes(String::from("a.png"), vec![1, 2, 3]),
+        types::InputFile::FilePath(String::from("b.mp4")),
+        types::InputFile::FileID(String::from("file-id")),
+    ];
+    let parts: Vec<_> = files
+        .iter()
+        .map(|file| block_on(file.data_with(&backend)).unwrap())
+        .collect();
+    assert!(matches!(parts[0], types::InputFilePart::Part(3)));
+    assert!(matches!(&parts[2], types::InputFilePart::Text(id) if id == "file-id"));
+    assert_eq!(*backend.names.borrow(), vec!["a.png", "b.mp4"]);
+}
+
+#[cfg(feature = "reqwest-backend")]
+#[tokio::test]
+async fn local_size_reports_file_length() {
+    let path = std::env::temp_dir().join(format!("local_size_{}.bin", std::process::id()));
+    tokio::fs::write(&path, vec![0u8; 1234]).await.unwrap();
+    let file = types::InputFile::FilePath(path.to_string_lossy().into_owned());
+    assert_eq!(file.local_size().await, Some(1234));
+    tokio::fs::remove_file(&path).await.unwrap();
+
+    let file = types::InputFile::FileID(String::from("file-id"));
+    assert_eq!(file.local_size().await, None);
+}
@@ -0,0 +1,281 @@
+use serde::de::DeserializeOwned;
+use telegram_bot_api::types;
+
+/// Loads a recorded API response from tests/fixtures and deserializes its result.
+fn load<T: DeserializeOwned>(name: &str) -> T {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let data = std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path, err));
+    let mut response: serde_json::Value = serde_json::from_str(&data).unwrap();
+    assert_eq!(
+        response["ok"], true,
+        "{} is not a successful response",
+        name
+    );
+    serde_json::from_value(response["result"].take())
+        .unwrap_or_else(|err| panic!("{} does not match its type: {}", name, err))
+}
+
+#[test]
+fn get_me() {
+    let user: types::User = load("get_me.json");
+    assert!(user.is_bot);
+    assert_eq!(user.supports_inline_queries, Some(true));
+}
+
+#[test]
+fn get_me_web_app() {
+    let user: types::User = load("get_me_web_app.json");
+    assert_eq!(user.has_main_web_app, Some(true));
+    assert_eq!(user.can_connect_to_business, Some(true));
+
+    let user: types::User = load("get_me.json");
+    assert_eq!(user.has_main_web_app, None);
+}
+
+#[test]
+fn get_chat() {
+    let chat: types::ChatFullInfo = load("get_chat.json");
+    assert!(chat.is_super_group());
+    assert_eq!(chat.slow_mode_delay, Some(10));
+    assert!(chat.pinned_message.is_some());
+}
+
+#[test]
+fn message_with_photo() {
+    let message: types::Message = load("message_photo.json");
+    assert!(message.chat.is_private());
+    assert_eq!(message.photo.map(|sizes| sizes.len()), Some(2));
+}
+
+#[test]
+fn message_with_both_thumbnail_keys() {
+    let message: types::Message = load("message_document_thumbnails.json");
+    let document = message.document.unwrap();
+    assert!(document.thumb.is_some());
+    assert_eq!(document.thumbnail().map(|t| t.width), Some(240));
+}
+
+#[test]
+fn callback_query() {
+    let query: types::CallbackQuery = load("callback_query.json");
+    assert_eq!(query.data.as_deref(), Some("yes"));
+    assert!(query.message.and_then(|m| m.reply_markup).is_some());
+}
+
+#[test]
+fn inline_query() {
+    let query: types::InlineQuery = load("inline_query.json");
+    assert_eq!(query.query, "rust");
+    assert_eq!(query.chat_type.as_deref(), Some("sender"));
+}
+
+#[test]
+fn message_origins() {
+    let origins: Vec<types::MessageOrigin> = load("message_origins.json");
+    assert!(matches!(
+        &origins[0],
+        types::MessageOrigin::MessageOriginUser(o) if o.sender_user.first_name == "Alice"
+    ));
+    assert!(matches!(
+        &origins[1],
+        types::MessageOrigin::MessageOriginHiddenUser(o) if o.sender_user_name == "Bob"
+    ));
+    assert!(matches!(
+        &origins[2],
+        types::MessageOrigin::MessageOriginChat(o) if o.author_signature.as_deref() == Some("admin")
+    ));
+    assert!(matches!(
+        &origins[3],
+        types::MessageOrigin::MessageOriginChannel(o) if o.message_id == 42
+    ));
+}
+
+#[test]
+#[allow(deprecated)]
+fn legacy_forward_fields() {
+    let mut message: types::Message = load("message_photo.json");
+    assert!(message.forward_origin().is_none());
+    message.forward_date = Some(1666000000);
+    message.forward_sender_name = Some(String::from("Bob"));
+    assert!(matches!(
+        message.forward_origin(),
+        Some(types::MessageOrigin::MessageOriginHiddenUser(o)) if o.sender_user_name == "Bob"
+    ));
+}
+
+#[test]
+fn chat_member_restricted_granular_permissions() {
+    let member: types::ChatMember = load("chat_member_restricted.json");
+    let types::ChatMember::ChatMemberRestricted(restricted) = member else {
+        panic!("expected a restricted member");
+    };
+    assert_eq!(restricted.can_send_photos, Some(true));
+    assert_eq!(restricted.can_send_documents, Some(false));
+    assert!(!restricted.can_send_media_messages);
+}
+
+#[test]
+fn poll_answers() {
+    let answers: Vec<types::PollAnswer> = load("poll_answers.json");
+    assert!(!answers[0].is_anonymous_vote());
+    assert_eq!(answers[0].user.first_name, "Alice");
+    assert!(answers[1].is_anonymous_vote());
+    assert_eq!(
+        answers[1].voter_chat().map(|chat| chat.id),
+        Some(-1009876543210)
+    );
+}
+
+#[test]
+fn user_chat_boosts() {
+    let boosts: types::UserChatBoosts = load("user_chat_boosts.json");
+    assert!(matches!(
+        &boosts.boosts[0].source,
+        types::ChatBoostSource::ChatBoostSourcePremium(s) if s.user.first_name == "Alice"
+    ));
+    assert!(matches!(
+        &boosts.boosts[1].source,
+        types::ChatBoostSource::ChatBoostSourceGiftCode(s) if s.user.id == 987654321
+    ));
+}
+
+#[test]
+fn may_be_message() {
+    let edited: types::MayBeMessage = load("message_photo.json");
+    assert!(edited.is_success());
+    assert_eq!(edited.message().map(|m| m.message_id), Some(1024));
+
+    let inline: types::MayBeMessage = serde_json::from_str("true").unwrap();
+    assert!(inline.is_success());
+    assert!(inline.message().is_none());
+    let failed: types::MayBeMessage = serde_json::from_str("false").unwrap();
+    assert!(!failed.is_success());
+}
+
+#[test]
+fn message_with_external_reply() {
+    let message: types::Message = load("message_external_reply.json");
+    let reply = message.external_reply.expect("external_reply");
+    assert!(reply.is_media());
+    assert_eq!(reply.has_media_spoiler, Some(true));
+    assert_eq!(reply.message_id, Some(77));
+    assert!(matches!(
+        reply.origin,
+        types::MessageOrigin::MessageOriginChannel(o) if o.chat.id == -1009876543210
+    ));
+    assert_eq!(
+        message.quote.map(|q| q.text).as_deref(),
+        Some("release notes")
+    );
+}
+
+#[test]
+fn stickers() {
+    let stickers: Vec<types::Sticker> = load("stickers.json");
+    let premium = &stickers[0];
+    assert!(premium.is_premium());
+    assert_eq!(premium.thumbnail().map(|t| t.width), Some(128));
+
+    let emoji = &stickers[1];
+    assert!(!emoji.is_premium());
+    assert_eq!(emoji.type_name, "custom_emoji");
+    assert_eq!(
+        emoji.custom_emoji_id.as_deref(),
+        Some("5368324170671202286")
+    );
+    assert_eq!(emoji.thumbnail().map(|t| t.width), Some(100));
+}
+
+#[test]
+fn callback_query_answer() {
+    let query: types::CallbackQuery = load("callback_query.json");
+    let answer = query.answer();
+    assert_eq!(answer.callback_query_id, query.id);
+    assert!(answer.text.is_none());
+    let answer = query.answer_text(String::from("Saved"));
+    assert_eq!(answer.callback_query_id, query.id);
+    assert_eq!(answer.text.as_deref(), Some("Saved"));
+}
+
+#[test]
+fn inline_query_answer() {
+    let query: types::InlineQuery = load("inline_query.json");
+    let result = types::InlineQueryResult::InlineQueryResultCachedSticker(
+        types::InlineQueryResultCachedSticker::new(String::from("1"), String::from("file-id")),
+    );
+    let answer = query.answer(vec![result]);
+    assert_eq!(answer.inline_query_id, query.id);
+    assert_eq!(answer.results.len(), 1);
+    assert_eq!(answer.results[0].id(), "1");
+    let empty = query.answer_empty();
+    assert_eq!(empty.inline_query_id, query.id);
+    assert!(empty.results.is_empty());
+}
+
+#[test]
+fn get_chat_channel() {
+    let chat: types::ChatFullInfo = load("get_chat_channel.json");
+    assert!(chat.is_channel());
+    assert_eq!(chat.accent_color_id, Some(5));
+    assert_eq!(chat.profile_accent_color_id, Some(3));
+    assert_eq!(
+        chat.background_custom_emoji_id.as_deref(),
+        Some("5368324170671202286")
+    );
+    assert_eq!(chat.emoji_status_expiration_date, Some(1735689600));
+    assert_eq!(chat.max_reaction_count, Some(11));
+    assert_eq!(chat.can_send_paid_media, Some(true));
+    let reactions = chat.available_reactions.unwrap();
+    assert!(matches!(&reactions[0], types::ReactionType::ReactionTypeEmoji(r) if r.emoji == "👍"));
+    assert!(matches!(
+        &reactions[1],
+        types::ReactionType::ReactionTypeCustomEmoji(_)
+    ));
+}
+
+#[test]
+fn get_chat_private() {
+    let chat: types::ChatFullInfo = load("get_chat_private.json");
+    assert!(chat.is_private());
+    let birthdate = chat.birthdate.unwrap();
+    assert_eq!(
+        (birthdate.day, birthdate.month, birthdate.year),
+        (14, 2, Some(1990))
+    );
+    assert_eq!(chat.business_location.unwrap().address, "1 Market St");
+    assert_eq!(
+        chat.business_opening_hours.unwrap().opening_hours[0].closing_minute,
+        1020
+    );
+    assert_eq!(
+        chat.personal_chat.unwrap().username.as_deref(),
+        Some("alices_garden")
+    );
+}
+
+#[test]
+fn star_transactions() {
+    let transactions: types::StarTransactions = load("star_transactions.json");
+    let incoming = &transactions.transactions[0];
+    assert_eq!(incoming.amount, 50);
+    assert!(incoming.receiver.is_none());
+    match incoming.source.as_ref().unwrap() {
+        types::TransactionPartner::TransactionPartnerUser(partner) => {
+            assert_eq!(partner.user.id, 123456789);
+            assert_eq!(partner.invoice_payload.as_deref(), Some("sticker-pack-7"));
+        }
+        other => panic!("unexpected source {:?}", other),
+    }
+    assert!(matches!(
+        transactions.transactions[1].receiver,
+        Some(types::TransactionPartner::TransactionPartnerFragment(_))
+    ));
+}
+
+#[test]
+fn message_in_forum_topic() {
+    let message: types::Message = load("message_forum_topic.json");
+    assert_eq!(message.is_topic_message, Some(true));
+    assert_eq!(message.message_thread_id, Some(498));
+    assert_eq!(message.thread_id(), Some(498));
+}
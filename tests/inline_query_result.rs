@@ -0,0 +1,57 @@
+use telegram_bot_api::types::{self, InlineQueryResult};
+
+fn markup() -> types::InlineKeyboardMarkup {
+    let mut button = types::InlineKeyboardButton::new(String::from("open"));
+    button.url = Some(String::from("https://example.com"));
+    types::InlineKeyboardMarkup::new(vec![vec![button]])
+}
+
+#[test]
+fn article_reply_markup_and_id() {
+    let article =
+        InlineQueryResult::InlineQueryResultArticle(types::InlineQueryResultArticle::new(
+            String::from("article-1"),
+            String::from("Title"),
+            types::InputMessageContent::InputTextMessageContent(
+                types::InputTextMessageContent::new(String::from("hello")),
+            ),
+        ));
+    assert_eq!(article.id(), "article-1");
+    let InlineQueryResult::InlineQueryResultArticle(article) = article.with_reply_markup(markup())
+    else {
+        panic!("variant changed");
+    };
+    assert_eq!(
+        article.reply_markup.map(|m| m.inline_keyboard.len()),
+        Some(1)
+    );
+}
+
+#[test]
+fn cached_sticker_reply_markup_and_id() {
+    let sticker = InlineQueryResult::InlineQueryResultCachedSticker(
+        types::InlineQueryResultCachedSticker::new(String::from("sticker-1"), String::from("file")),
+    );
+    assert_eq!(sticker.id(), "sticker-1");
+    let InlineQueryResult::InlineQueryResultCachedSticker(sticker) =
+        sticker.with_reply_markup(markup())
+    else {
+        panic!("variant changed");
+    };
+    assert!(sticker.reply_markup.is_some());
+}
+
+#[test]
+fn cached_sticker_omits_input_message_content() {
+    let sticker = InlineQueryResult::InlineQueryResultCachedSticker(
+        types::InlineQueryResultCachedSticker::new(
+            String::from("sticker-1"),
+            String::from("CAACAgIAAxkBAAEB"),
+        ),
+    );
+    let value = serde_json::to_value(&sticker).unwrap();
+    assert!(value.get("input_message_content").is_none());
+    assert!(value.get("reply_markup").is_none());
+    assert_eq!(value["type"], "sticker");
+    assert_eq!(value["sticker_file_id"], "CAACAgIAAxkBAAEB");
+}
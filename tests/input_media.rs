@@ -0,0 +1,196 @@
+use telegram_bot_api::types;
+
+#[test]
+fn from_path_needs_upload() {
+    let photo = types::InputMediaPhoto::from_path(String::from("logo_256.png"));
+    assert!(photo.media.need_upload());
+    let video = types::InputMediaVideo::from_path(String::from("clip.mp4"));
+    assert!(video.media.need_upload());
+    let document = types::InputMediaDocument::from_path(String::from("report.pdf"));
+    assert!(document.media.need_upload());
+}
+
+#[test]
+fn wrapping_constructors() {
+    let media = types::InputMedia::photo(types::InputFile::FilePath(String::from("a.png")));
+    assert!(matches!(media, types::InputMedia::InputMediaPhoto(p) if p.media.need_upload()));
+    let media = types::InputMedia::video(types::InputFile::FileID(String::from("file-id")));
+    assert!(matches!(media, types::InputMedia::InputMediaVideo(v) if !v.media.need_upload()));
+}
+
+#[test]
+fn media_group_keeps_captions() {
+    use telegram_bot_api::methods::{self, Methods, Params};
+
+    let media: Vec<types::InputMedia> = ["first", "second", "third"]
+        .iter()
+        .enumerate()
+        .map(|(idx, caption)| {
+            let mut photo = types::InputMediaPhoto::from_path(format!("photo-{}.png", idx));
+            photo.caption = Some(caption.to_string());
+            photo.parse_mode = Some(String::from("HTML"));
+            types::InputMedia::InputMediaPhoto(photo)
+        })
+        .collect();
+    let mut request = methods::SendMediaGroup::new(types::ChatId::IntType(1), media);
+    request.protect_content = Some(true);
+
+    let params = request.params().unwrap();
+    assert_eq!(params["protect_content"], true);
+    for (idx, caption) in ["first", "second", "third"].iter().enumerate() {
+        assert_eq!(params["media"][idx]["caption"], *caption);
+        assert_eq!(params["media"][idx]["parse_mode"], "HTML");
+        assert_eq!(
+            params["media"][idx]["media"],
+            format!("attach://file-{}", idx)
+        );
+    }
+    assert_eq!(request.files().len(), 3);
+}
+
+#[test]
+fn paid_media_album_files() {
+    use telegram_bot_api::methods::{self, Methods, Params};
+
+    let mut video =
+        types::InputPaidMediaVideo::new(types::InputFile::FilePath(String::from("clip.mp4")));
+    video.thumb = Some(types::InputFile::FilePath(String::from("thumb.jpg")));
+    let media = vec![
+        types::InputPaidMedia::photo(types::InputFile::FilePath(String::from("a.png"))),
+        types::InputPaidMedia::InputPaidMediaVideo(video),
+    ];
+    let request = methods::SendPaidMedia::new(types::ChatId::IntType(1), 25, media);
+
+    let files = request.files();
+    assert_eq!(files.len(), 3);
+    assert!(files.contains_key("file-0"));
+    assert!(files.contains_key("file-1"));
+    assert!(files.contains_key("file-1-thumb"));
+
+    let params = request.params().unwrap();
+    assert_eq!(params["star_count"], 25);
+    assert_eq!(params["media"][0]["type"], "photo");
+    assert_eq!(params["media"][0]["media"], "attach://file-0");
+    assert_eq!(params["media"][1]["type"], "video");
+    assert_eq!(params["media"][1]["thumbnail"], "attach://file-1-thumb");
+}
+
+#[test]
+fn show_caption_above_media() {
+    use telegram_bot_api::methods::{self, Params};
+
+    let mut request = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("file-id")),
+    );
+    assert!(!request
+        .params()
+        .unwrap()
+        .contains_key("show_caption_above_media"));
+    request.show_caption_above_media = Some(true);
+    assert_eq!(request.params().unwrap()["show_caption_above_media"], true);
+
+    let mut photo = types::InputMediaPhoto::from_path(String::from("a.png"));
+    photo.show_caption_above_media = Some(true);
+    let mut video = types::InputMediaVideo::from_path(String::from("b.mp4"));
+    video.show_caption_above_media = Some(true);
+    let request = methods::SendMediaGroup::new(
+        types::ChatId::IntType(1),
+        vec![
+            types::InputMedia::InputMediaPhoto(photo),
+            types::InputMedia::InputMediaVideo(video),
+        ],
+    );
+    let params = request.params().unwrap();
+    assert_eq!(params["media"][0]["media"], "attach://file-0");
+    assert_eq!(params["media"][0]["show_caption_above_media"], true);
+    assert_eq!(params["media"][1]["show_caption_above_media"], true);
+}
+
+#[test]
+fn send_photo_by_url_skips_multipart() {
+    use telegram_bot_api::methods::{self, Methods, Params};
+
+    let request = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileURL(String::from("https://example.com/cat.jpg")),
+    );
+    assert!(request.files().is_empty());
+    assert_eq!(
+        request.params().unwrap()["photo"],
+        "https://example.com/cat.jpg"
+    );
+
+    let mut request = methods::SendVideo::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FilePath(String::from("clip.mp4")),
+    );
+    request.thumb = Some(types::InputFile::FileID(String::from("thumb-id")));
+    let files = request.files();
+    assert_eq!(files.len(), 1);
+    assert!(files.contains_key("video"));
+    let params = request.params().unwrap();
+    assert!(!params.contains_key("video"));
+    assert_eq!(params["thumbnail"], "thumb-id");
+}
+
+#[test]
+fn send_document_files_only_for_uploads() {
+    use telegram_bot_api::methods::{self, Methods, Params};
+
+    let request = methods::SendDocument::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("doc-id")),
+    );
+    assert!(request.files().is_empty());
+    assert_eq!(request.params().unwrap()["document"], "doc-id");
+
+    let request = methods::SendDocument::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FilePath(String::from("report.pdf")),
+    );
+    let files = request.files();
+    assert!(matches!(
+        files.get("document"),
+        Some(types::InputFile::FilePath(path)) if path == "report.pdf"
+    ));
+    assert!(!request.params().unwrap().contains_key("document"));
+}
+
+#[test]
+fn has_spoiler_omitted_by_default() {
+    use telegram_bot_api::methods;
+
+    let photo = types::InputMediaPhoto::new(types::InputFile::FileID(String::from("photo-id")));
+    assert!(serde_json::to_value(&photo)
+        .unwrap()
+        .get("has_spoiler")
+        .is_none());
+    let request = methods::SendVideo::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("video-id")),
+    );
+    assert!(serde_json::to_value(&request)
+        .unwrap()
+        .get("has_spoiler")
+        .is_none());
+    let request = methods::SendAnimation::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("animation-id")),
+    );
+    assert!(serde_json::to_value(&request)
+        .unwrap()
+        .get("has_spoiler")
+        .is_none());
+}
+
+#[test]
+fn has_spoiler_survives_prepare_input_media_param() {
+    let mut video =
+        types::InputMediaVideo::new(types::InputFile::FilePath(String::from("clip.mp4")));
+    video.has_spoiler = Some(true);
+    let prepared = types::InputMedia::InputMediaVideo(video).prepare_input_media_param(0);
+    let value = serde_json::to_value(&prepared).unwrap();
+    assert_eq!(value["has_spoiler"], true);
+    assert_eq!(value["media"], "attach://file-0");
+}
@@ -0,0 +1,32 @@
+use telegram_bot_api::types::{self, InputMessageContent, InputMessageContentKind};
+
+fn round_trip(content: InputMessageContent) -> InputMessageContent {
+    serde_json::from_value(serde_json::to_value(&content).unwrap()).unwrap()
+}
+
+#[test]
+fn venue_round_trips_as_venue() {
+    let venue = types::InputVenueMessageContent::new(
+        51.5,
+        -0.12,
+        String::from("Office"),
+        String::from("1 Example Street"),
+    );
+    let content = round_trip(InputMessageContent::InputVenueMessageContent(venue));
+    assert_eq!(content.content_kind(), InputMessageContentKind::Venue);
+}
+
+#[test]
+fn location_and_text_round_trip() {
+    let location = types::InputLocationMessageContent::new(51.5, -0.12);
+    let content = round_trip(InputMessageContent::InputLocationMessageContent(location));
+    assert_eq!(content.content_kind(), InputMessageContentKind::Location);
+
+    let text = types::InputTextMessageContent::new(String::from("hello"));
+    let content = round_trip(InputMessageContent::InputTextMessageContent(text));
+    assert_eq!(content.content_kind(), InputMessageContentKind::Text);
+
+    let contact = types::InputContactMessageContent::new(String::from("+100"), String::from("Al"));
+    let content = round_trip(InputMessageContent::InputContactMessageContent(contact));
+    assert_eq!(content.content_kind(), InputMessageContentKind::Contact);
+}
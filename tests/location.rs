@@ -0,0 +1,19 @@
+use telegram_bot_api::types;
+
+#[test]
+fn distance_along_meridian() {
+    let a = types::Location::new(0.0, 0.0);
+    let b = types::Location::new(0.0, 1.0);
+    // one degree of latitude is about 111.2 km
+    assert!((a.distance_to(&b) - 111_195.0).abs() < 10.0);
+    assert!((b.distance_to(&a) - a.distance_to(&b)).abs() < 1e-6);
+    assert_eq!(a.distance_to(&a), 0.0);
+}
+
+#[test]
+fn distance_between_cities() {
+    let london = types::Location::new(-0.1278, 51.5074);
+    let paris = types::Location::new(2.3522, 48.8566);
+    // about 343.5 km
+    assert!((london.distance_to(&paris) - 343_500.0).abs() < 1_000.0);
+}
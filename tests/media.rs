@@ -0,0 +1,133 @@
+use telegram_bot_api::types::{self, HasDuration, HasFileSize, TelegramFile};
+
+#[test]
+fn duration_formatted() {
+    let voice = types::Voice::new(String::from("id"), String::from("uid"), 65);
+    assert_eq!(voice.duration_formatted(), "1:05");
+    let video = types::Video::new(String::from("id"), String::from("uid"), 640, 480, 3661);
+    assert_eq!(video.duration_formatted(), "1:01:01");
+}
+
+#[test]
+fn file_size_mb() {
+    let mut document = types::Document::new(String::from("id"), String::from("uid"));
+    assert_eq!(document.file_size_mb(), None);
+    document.file_size = Some(5 * 1024 * 1024 / 2);
+    assert_eq!(document.file_size_mb(), Some(2.5));
+    let mut photo = types::PhotoSize::new(String::from("id"), String::from("uid"), 90, 67);
+    photo.file_size = Some(1024 * 1024);
+    assert_eq!(HasFileSize::file_size(&photo), Some(1024 * 1024));
+    assert_eq!(photo.file_size_mb(), Some(1.0));
+}
+
+#[test]
+fn document_to_get_file() {
+    let document = types::Document::new(String::from("doc-id"), String::from("doc-uid"));
+    assert_eq!(TelegramFile::file_id(&document), "doc-id");
+    assert_eq!(TelegramFile::file_unique_id(&document), "doc-uid");
+    assert_eq!(document.to_get_file().file_id, "doc-id");
+}
+
+#[test]
+fn file_size_accepts_numeric_string() {
+    let document: types::Document =
+        serde_json::from_str(r#"{"file_id":"id","file_unique_id":"uid","file_size":"2048"}"#)
+            .unwrap();
+    assert_eq!(HasFileSize::file_size(&document), Some(2048));
+    let document: types::Document =
+        serde_json::from_str(r#"{"file_id":"id","file_unique_id":"uid","file_size":4096}"#)
+            .unwrap();
+    assert_eq!(HasFileSize::file_size(&document), Some(4096));
+    let document: types::Document =
+        serde_json::from_str(r#"{"file_id":"id","file_unique_id":"uid"}"#).unwrap();
+    assert_eq!(HasFileSize::file_size(&document), None);
+}
+
+#[test]
+fn file_download() {
+    let mut file = types::File::new(String::from("id"), String::from("uid"));
+    assert_eq!(file.suggested_filename(), None);
+    assert!(types::DownloadFile::new(&file, "123:abc").is_none());
+
+    file.file_path = Some(String::from("photos/file_1.jpg"));
+    assert_eq!(file.suggested_filename(), Some("file_1.jpg"));
+    assert_eq!(
+        file.download_stream_url("123:abc").as_deref(),
+        Some("https://api.telegram.org/file/bot123:abc/photos/file_1.jpg")
+    );
+    let download = types::DownloadFile::new(&file, "123:abc").unwrap();
+    assert_eq!(download.url, file.download_url("123:abc").unwrap());
+    assert_eq!(download.filename.as_deref(), Some("file_1.jpg"));
+}
+
+#[test]
+fn to_input_media_keeps_file_id() {
+    fn file_id(media: &types::InputFile) -> &str {
+        match media {
+            types::InputFile::FileID(id) => id,
+            other => panic!("expected a file id, got {:?}", other),
+        }
+    }
+
+    let photo = types::PhotoSize::new(String::from("photo-id"), String::from("uid"), 90, 67);
+    assert_eq!(file_id(&photo.to_input_media().media), "photo-id");
+    let video = types::Video::new(String::from("video-id"), String::from("uid"), 640, 480, 12);
+    let input = video.to_input_media();
+    assert_eq!(file_id(&input.media), "video-id");
+    assert_eq!((input.width, input.duration), (Some(640), Some(12)));
+    let audio = types::Audio::new(String::from("audio-id"), String::from("uid"), 200);
+    assert_eq!(file_id(&audio.to_input_media().media), "audio-id");
+    let document = types::Document::new(String::from("doc-id"), String::from("uid"));
+    assert_eq!(file_id(&document.to_input_media().media), "doc-id");
+    let animation = types::Animation::new(String::from("gif-id"), String::from("uid"), 320, 240, 3);
+    assert_eq!(file_id(&animation.to_input_media().media), "gif-id");
+}
+
+#[test]
+fn document_thumbnail_alias() {
+    let thumb = serde_json::json!({
+        "file_id": "AAMCdocthumb",
+        "file_unique_id": "AQADdocthumb",
+        "width": 90,
+        "height": 120
+    });
+    for key in ["thumbnail", "thumb"] {
+        let mut value = serde_json::json!({
+            "file_id": "BQACAgdoc",
+            "file_unique_id": "AgADdoc",
+            "file_name": "report.pdf"
+        });
+        value[key] = thumb.clone();
+        let document: types::Document = serde_json::from_value(value).unwrap();
+        assert_eq!(document.thumbnail().map(|t| t.height), Some(120), "{}", key);
+    }
+}
+
+#[test]
+fn thumbnail_preferred_over_thumb() {
+    let video: types::Video = serde_json::from_value(serde_json::json!({
+        "file_id": "BAACAgvideo",
+        "file_unique_id": "AgADvideo",
+        "width": 1280,
+        "height": 720,
+        "duration": 12,
+        "thumbnail": {"file_id": "new", "file_unique_id": "new", "width": 320, "height": 180},
+        "thumb": {"file_id": "old", "file_unique_id": "old", "width": 90, "height": 51}
+    }))
+    .unwrap();
+    assert_eq!(video.thumbnail().map(|t| t.width), Some(320));
+
+    let media: types::InputMediaVideo = serde_json::from_value(serde_json::json!({
+        "media": "video-id",
+        "thumbnail": "new-thumb-id",
+        "thumb": "old-thumb-id"
+    }))
+    .unwrap();
+    assert!(matches!(
+        media.thumbnail(),
+        Some(types::InputFile::FileID(id)) if id == "new-thumb-id"
+    ));
+    let value = serde_json::to_value(&media).unwrap();
+    assert_eq!(value["thumbnail"], "new-thumb-id");
+    assert!(value.get("thumb").is_none());
+}
@@ -0,0 +1,36 @@
+use telegram_bot_api::types::{self, MenuButton};
+
+fn type_tag(button: &MenuButton) -> String {
+    serde_json::to_value(button).unwrap()["type"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn commands() {
+    let button = MenuButton::commands();
+    assert_eq!(type_tag(&button), "commands");
+    assert_eq!(button.kind(), "commands");
+}
+
+#[test]
+fn default_button() {
+    let button = MenuButton::default_button();
+    assert_eq!(type_tag(&button), "default");
+    assert_eq!(button.kind(), "default");
+}
+
+#[test]
+fn web_app() {
+    let button = MenuButton::web_app(
+        String::from("Open"),
+        types::WebAppInfo::new(String::from("https://example.com/app")),
+    );
+    assert_eq!(type_tag(&button), "web_app");
+    assert_eq!(button.kind(), "web_app");
+    assert_eq!(
+        serde_json::to_value(&button).unwrap()["web_app"]["url"],
+        "https://example.com/app"
+    );
+}
@@ -0,0 +1,424 @@
+use serde_json::json;
+use telegram_bot_api::types;
+
+fn group() -> serde_json::Value {
+    json!({"id": -1001234567890_i64, "title": "Rust Group", "type": "supergroup"})
+}
+
+#[test]
+fn group_message_from_bot() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 1,
+        "from": {"id": 42, "is_bot": true, "first_name": "Helper", "username": "helper_bot"},
+        "chat": group(),
+        "date": 1700000000,
+        "text": "beep"
+    }))
+    .unwrap();
+    assert_eq!(message.sender_is_bot(), Some(true));
+    assert_eq!(message.sender_id(), Some(42));
+    assert!(!message.is_anonymous_admin());
+}
+
+#[test]
+fn anonymous_admin_message() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 2,
+        "sender_chat": group(),
+        "chat": group(),
+        "date": 1700000000,
+        "text": "announcement"
+    }))
+    .unwrap();
+    assert_eq!(message.sender_is_bot(), None);
+    assert_eq!(message.sender_id(), None);
+    assert!(message.is_anonymous_admin());
+}
+
+#[test]
+fn anonymous_admin_message_with_fake_sender() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 3,
+        "from": {
+            "id": 1087968824,
+            "is_bot": true,
+            "first_name": "Group",
+            "username": "GroupAnonymousBot"
+        },
+        "sender_chat": group(),
+        "chat": group(),
+        "date": 1700000000,
+        "text": "announcement"
+    }))
+    .unwrap();
+    assert_eq!(message.sender_id(), Some(1087968824));
+    assert!(message.is_anonymous_admin());
+}
+
+#[test]
+fn channel_post_is_not_anonymous_admin() {
+    let channel = json!({"id": -1009876543210_i64, "title": "News", "type": "channel"});
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 4,
+        "sender_chat": channel,
+        "chat": channel,
+        "date": 1700000000,
+        "text": "release notes"
+    }))
+    .unwrap();
+    assert!(!message.is_anonymous_admin());
+}
+
+#[test]
+fn forward_and_copy_from_source_chat() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 7,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "hello"
+    }))
+    .unwrap();
+    let forward = message.forward_to(types::ChatId::StringType(String::from("@archive")));
+    assert_eq!(forward.from_chat_id.as_i64(), Some(-1001234567890));
+    assert_eq!(forward.message_id, 7);
+    assert_eq!(forward.chat_id.as_username(), Some("@archive"));
+    let copy = message.copy_to(types::ChatId::IntType(1));
+    assert_eq!(copy.from_chat_id.as_i64(), Some(-1001234567890));
+    assert_eq!(copy.message_id, 7);
+}
+
+#[test]
+fn constructed_message_round_trips_chat_type() {
+    let message = types::Message::new(1, 0, Box::new(types::Chat::new_super_group(-100)));
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["chat"]["type"], "supergroup");
+    let decoded: types::Message = serde_json::from_value(value).unwrap();
+    assert!(matches!(
+        decoded.chat.chat_type(),
+        types::ChatType::Supergroup
+    ));
+    let value = serde_json::to_value(&decoded).unwrap();
+    assert_eq!(value["chat"]["type"], "supergroup");
+    assert_eq!(value["chat"]["id"], -100);
+}
+
+#[test]
+fn message_from_booster() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 5,
+        "from": {"id": 7, "is_bot": false, "first_name": "Booster"},
+        "sender_boost_count": 3,
+        "chat": group(),
+        "date": 1700000000,
+        "is_from_offline": true,
+        "text": "thanks for the perks"
+    }))
+    .unwrap();
+    assert_eq!(message.sender_boost_count(), 3);
+    assert_eq!(message.is_from_offline, Some(true));
+
+    let serialized = serde_json::to_value(&message).unwrap();
+    assert_eq!(serialized["sender_boost_count"], 3);
+    let plain: types::Message = serde_json::from_value(json!({
+        "message_id": 6,
+        "chat": group(),
+        "date": 1700000000
+    }))
+    .unwrap();
+    assert_eq!(plain.sender_boost_count(), 0);
+    assert!(serde_json::to_value(&plain)
+        .unwrap()
+        .get("is_from_offline")
+        .is_none());
+}
+
+#[test]
+fn web_app_data_parse_json() {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Order {
+        action: String,
+        id: i64,
+    }
+
+    let data = types::WebAppData::new(
+        String::from(r#"{"action":"buy","id":5}"#),
+        String::from("Shop"),
+    );
+    let order: Order = data.parse_json().unwrap();
+    assert_eq!(
+        order,
+        Order {
+            action: String::from("buy"),
+            id: 5
+        }
+    );
+
+    let data = types::WebAppData::new(String::from("not json"), String::from("Shop"));
+    assert!(data.parse_json::<Order>().is_err());
+}
+
+#[test]
+fn custom_emoji_ids_are_deduplicated() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 8,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "🙂 🚀 🙂",
+        "entities": [
+            {"type": "custom_emoji", "offset": 0, "length": 2, "custom_emoji_id": "111"},
+            {"type": "custom_emoji", "offset": 3, "length": 2, "custom_emoji_id": "222"},
+            {"type": "custom_emoji", "offset": 6, "length": 2, "custom_emoji_id": "111"},
+            {"type": "bold", "offset": 0, "length": 2}
+        ]
+    }))
+    .unwrap();
+    assert_eq!(message.custom_emoji_ids(), vec!["111", "222"]);
+    let request = message.resolve_custom_emojis().unwrap();
+    assert_eq!(request.custom_emoji_ids, vec!["111", "222"]);
+
+    let plain = types::Message::new(9, 0, Box::new(types::Chat::new_super_group(-100)));
+    assert!(plain.custom_emoji_ids().is_empty());
+    assert!(plain.resolve_custom_emojis().is_none());
+}
+
+#[test]
+fn reply_text_targets_topic() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 10,
+        "message_thread_id": 5,
+        "is_topic_message": true,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "in a topic"
+    }))
+    .unwrap();
+    let reply = message.reply_text(String::from("pong"));
+    assert_eq!(reply.message_thread_id, Some(5));
+    assert_eq!(reply.reply_to_message_id, Some(10));
+    let value = serde_json::to_value(&reply).unwrap();
+    assert_eq!(value["message_thread_id"], 5);
+}
+
+#[test]
+fn reply_text_omits_thread_outside_topics() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 11,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "plain"
+    }))
+    .unwrap();
+    let reply = message.reply_text(String::from("pong"));
+    assert_eq!(reply.message_thread_id, None);
+    let value = serde_json::to_value(&reply).unwrap();
+    assert!(value.get("message_thread_id").is_none());
+}
+
+#[test]
+fn reply_text_omits_reply_thread_in_non_forum_group() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 12,
+        "message_thread_id": 4,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "a reply in a thread"
+    }))
+    .unwrap();
+    let reply = message.reply_text(String::from("pong"));
+    assert_eq!(reply.message_thread_id, None);
+    let photo = message.reply_photo(types::InputFile::FileID(String::from("photo-id")));
+    assert_eq!(photo.message_thread_id, None);
+}
+
+#[test]
+fn forwarded_story_message() {
+    let channel = json!({"id": -1009876543210_i64, "title": "News", "type": "channel"});
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 13,
+        "from": {"id": 42, "is_bot": false, "first_name": "Ada"},
+        "chat": group(),
+        "date": 1700000000,
+        "forward_from_chat": channel,
+        "forward_date": 1699990000,
+        "story": {"chat": channel, "id": 31}
+    }))
+    .unwrap();
+    let story = message.story.as_ref().unwrap();
+    assert_eq!(story.id, 31);
+    assert_eq!(story.chat.id, -1009876543210);
+    assert!(message.text.is_none());
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["story"]["id"], 31);
+
+    let plain = types::Message::new(14, 0, Box::new(types::Chat::new_super_group(-100)));
+    assert!(serde_json::to_value(&plain).unwrap().get("story").is_none());
+}
+
+#[test]
+fn giveaway_messages() {
+    let channel = json!({"id": -1009876543210_i64, "title": "News", "type": "channel"});
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 120,
+        "sender_chat": channel,
+        "chat": channel,
+        "date": 1700000000,
+        "giveaway": {
+            "chats": [channel],
+            "winners_selection_date": 1700600000,
+            "winner_count": 3,
+            "only_new_members": true,
+            "country_codes": ["DE", "NL"],
+            "premium_subscription_month_count": 6
+        }
+    }))
+    .unwrap();
+    let giveaway = message.giveaway.as_ref().unwrap();
+    assert_eq!(giveaway.winner_count, 3);
+    assert_eq!(giveaway.chats[0].id, -1009876543210);
+    assert_eq!(giveaway.only_new_members, Some(true));
+    assert_eq!(
+        giveaway.country_codes.as_deref(),
+        Some(&[String::from("DE"), String::from("NL")][..])
+    );
+    assert_eq!(giveaway.premium_subscription_month_count, Some(6));
+
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 150,
+        "chat": group(),
+        "date": 1700600100,
+        "giveaway_winners": {
+            "chat": channel,
+            "giveaway_message_id": 120,
+            "winners_selection_date": 1700600000,
+            "winner_count": 3,
+            "winners": [
+                {"id": 1001, "is_bot": false, "first_name": "Ada"},
+                {"id": 1002, "is_bot": false, "first_name": "Grace"}
+            ],
+            "premium_subscription_month_count": 6,
+            "unclaimed_prize_count": 1,
+            "only_new_members": true
+        }
+    }))
+    .unwrap();
+    let winners = message.giveaway_winners.as_ref().unwrap();
+    assert_eq!(winners.giveaway_message_id, 120);
+    assert_eq!(
+        winners
+            .winners
+            .iter()
+            .map(|user| user.id)
+            .collect::<Vec<_>>(),
+        vec![1001, 1002]
+    );
+    assert_eq!(winners.unclaimed_prize_count, Some(1));
+    assert_eq!(winners.was_refunded, None);
+}
+
+#[test]
+fn video_chat_events() {
+    let started: types::Message = serde_json::from_value(json!({
+        "message_id": 30,
+        "chat": group(),
+        "date": 1700000000,
+        "video_chat_started": {}
+    }))
+    .unwrap();
+    assert!(matches!(
+        started.video_chat_event(),
+        Some(types::VideoChatEvent::Started(_))
+    ));
+
+    let ended: types::Message = serde_json::from_value(json!({
+        "message_id": 31,
+        "chat": group(),
+        "date": 1700003600,
+        "video_chat_ended": {"duration": 3600}
+    }))
+    .unwrap();
+    assert!(matches!(
+        ended.video_chat_event(),
+        Some(types::VideoChatEvent::Ended(e)) if e.duration == 3600
+    ));
+
+    let scheduled: types::Message = serde_json::from_value(json!({
+        "message_id": 32,
+        "chat": group(),
+        "date": 1700000000,
+        "video_chat_scheduled": {"start_date": 1700090000}
+    }))
+    .unwrap();
+    assert!(matches!(
+        scheduled.video_chat_event(),
+        Some(types::VideoChatEvent::Scheduled(s)) if s.start_date == 1700090000
+    ));
+
+    let invited: types::Message = serde_json::from_value(json!({
+        "message_id": 33,
+        "chat": group(),
+        "date": 1700000000,
+        "video_chat_participants_invited": {
+            "users": [{"id": 7, "is_bot": false, "first_name": "Ada"}]
+        }
+    }))
+    .unwrap();
+    assert!(matches!(
+        invited.video_chat_event(),
+        Some(types::VideoChatEvent::ParticipantsInvited(i))
+            if i.users.len() == 1
+    ));
+
+    let text: types::Message = serde_json::from_value(json!({
+        "message_id": 34,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "hello"
+    }))
+    .unwrap();
+    assert!(text.video_chat_event().is_none());
+}
+
+#[test]
+fn thread_id_outside_topics() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 40,
+        "message_thread_id": 35,
+        "chat": group(),
+        "date": 1700000000,
+        "text": "reply in a thread"
+    }))
+    .unwrap();
+    assert_eq!(message.message_thread_id, Some(35));
+    assert_eq!(message.thread_id(), None);
+}
+
+#[test]
+fn quoted_reply_message() {
+    let message: types::Message = serde_json::from_value(json!({
+        "message_id": 50,
+        "from": {"id": 42, "is_bot": false, "first_name": "Ada"},
+        "chat": group(),
+        "date": 1700000000,
+        "reply_to_message": {
+            "message_id": 48,
+            "chat": group(),
+            "date": 1699999000,
+            "text": "the release notes are up"
+        },
+        "quote": {
+            "text": "release notes",
+            "entities": [{"type": "bold", "offset": 0, "length": 7}],
+            "position": 4,
+            "is_manual": true
+        },
+        "text": "thanks"
+    }))
+    .unwrap();
+    let quote = message.quote.unwrap();
+    assert_eq!(quote.text, "release notes");
+    assert_eq!(quote.position, 4);
+    assert_eq!(quote.is_manual, Some(true));
+    assert_eq!(quote.entities.map(|e| e.len()), Some(1));
+    assert_eq!(message.reply_to_message.map(|m| m.message_id), Some(48));
+    assert!(message.external_reply.is_none());
+}
@@ -0,0 +1,65 @@
+use telegram_bot_api::types;
+
+fn hash() -> String {
+    String::from("aGFzaA==")
+}
+
+fn message() -> String {
+    String::from("Please resend")
+}
+
+#[test]
+fn constructors_set_fixed_source() {
+    let data = types::PassportElementErrorDataField::new_passport(
+        String::from("document_no"),
+        hash(),
+        message(),
+    );
+    assert_eq!(data.source, "data");
+    assert_eq!(data.type_name, "passport");
+    assert_eq!(
+        types::PassportElementErrorFrontSide::new_identity_card(hash(), message()).source,
+        "front_side"
+    );
+    assert_eq!(
+        types::PassportElementErrorReverseSide::new_driver_license(hash(), message()).source,
+        "reverse_side"
+    );
+    assert_eq!(
+        types::PassportElementErrorSelfie::new_passport(hash(), message()).source,
+        "selfie"
+    );
+    assert_eq!(
+        types::PassportElementErrorFile::new_utility_bill(hash(), message()).source,
+        "file"
+    );
+    assert_eq!(
+        types::PassportElementErrorFiles::new_bank_statement(vec![hash()], message()).source,
+        "files"
+    );
+    assert_eq!(
+        types::PassportElementErrorTranslationFile::new_passport(hash(), message()).source,
+        "translation_file"
+    );
+    assert_eq!(
+        types::PassportElementErrorTranslationFiles::new_rental_agreement(vec![hash()], message())
+            .source,
+        "translation_files"
+    );
+    assert_eq!(
+        types::PassportElementErrorUnspecified::new(String::from("address"), hash(), message())
+            .source,
+        "unspecified"
+    );
+}
+
+#[test]
+fn source_is_serialized() {
+    let error = types::PassportElementErrorFrontSide::new_passport(hash(), message());
+    let value = serde_json::to_value(&error).unwrap();
+    assert_eq!(
+        value["source"],
+        types::PassportElementErrorFrontSide::SOURCE
+    );
+    assert_eq!(value["type"], "passport");
+}
@@ -0,0 +1,156 @@
+use serde_json::json;
+use telegram_bot_api::types::{
+    LabeledPrice, PreCheckoutQuery, ShippingOption, ShippingQuery, SuccessfulPayment,
+    STARS_CURRENCY,
+};
+
+fn shipping_query() -> ShippingQuery {
+    serde_json::from_value(json!({
+        "id": "sq-1",
+        "from": {"id": 1, "is_bot": false, "first_name": "Alice"},
+        "invoice_payload": "payload",
+        "shipping_address": {
+            "country_code": "US",
+            "state": "CA",
+            "city": "San Francisco",
+            "street_line1": "1 Market St",
+            "street_line2": "",
+            "post_code": "94105"
+        }
+    }))
+    .unwrap()
+}
+
+fn pre_checkout_query() -> PreCheckoutQuery {
+    serde_json::from_value(json!({
+        "id": "pcq-1",
+        "from": {"id": 1, "is_bot": false, "first_name": "Alice"},
+        "currency": "USD",
+        "total_amount": 1000,
+        "invoice_payload": "payload"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn shipping_query_answer_ok_sets_options() {
+    let option = ShippingOption::new(
+        "standard".to_string(),
+        "Standard".to_string(),
+        vec![LabeledPrice::new("Shipping".to_string(), 500)],
+    );
+    let answer = shipping_query().answer_ok(vec![option]);
+    assert_eq!(answer.shipping_query_id, "sq-1");
+    assert!(answer.ok);
+    assert_eq!(answer.shipping_options.unwrap().len(), 1);
+    assert!(answer.error_message.is_none());
+}
+
+#[test]
+fn shipping_query_answer_error_sets_message() {
+    let answer = shipping_query().answer_error("No delivery here".to_string());
+    assert_eq!(answer.shipping_query_id, "sq-1");
+    assert!(!answer.ok);
+    assert!(answer.shipping_options.is_none());
+    assert_eq!(answer.error_message.as_deref(), Some("No delivery here"));
+}
+
+#[test]
+fn pre_checkout_query_answer_ok() {
+    let answer = pre_checkout_query().answer_ok();
+    assert_eq!(answer.pre_checkout_query_id, "pcq-1");
+    assert!(answer.ok);
+    assert!(answer.error_message.is_none());
+}
+
+#[test]
+fn pre_checkout_query_answer_error_sets_message() {
+    let answer = pre_checkout_query().answer_error("Out of stock".to_string());
+    assert_eq!(answer.pre_checkout_query_id, "pcq-1");
+    assert!(!answer.ok);
+    assert_eq!(answer.error_message.as_deref(), Some("Out of stock"));
+}
+
+#[test]
+fn labeled_price_stars_uses_whole_amount() {
+    let price = LabeledPrice::stars("Sticker pack".to_string(), 50);
+    assert_eq!(price.label, "Sticker pack");
+    assert_eq!(price.amount, 50);
+}
+
+#[test]
+fn successful_payment_is_stars() {
+    let payment = SuccessfulPayment::new(
+        STARS_CURRENCY.to_string(),
+        50,
+        "payload".to_string(),
+        "charge".to_string(),
+        String::new(),
+    );
+    assert!(payment.is_stars());
+    let payment = SuccessfulPayment::new(
+        "USD".to_string(),
+        145,
+        "payload".to_string(),
+        "charge".to_string(),
+        "provider".to_string(),
+    );
+    assert!(!payment.is_stars());
+}
+
+#[test]
+fn refund_star_payment_params() {
+    use telegram_bot_api::methods::{Methods, Params, RefundStarPayment};
+
+    let request = RefundStarPayment::new(1, "charge".to_string());
+    assert_eq!(request.endpoint(), "refundStarPayment");
+    let params = request.params().unwrap();
+    assert_eq!(params["user_id"], 1);
+    assert_eq!(params["telegram_payment_charge_id"], "charge");
+}
+
+#[test]
+fn format_amounts() {
+    use telegram_bot_api::types::{format_amount, Invoice};
+
+    assert_eq!(format_amount("USD", 145), "1.45");
+    assert_eq!(format_amount("USD", 5), "0.05");
+    assert_eq!(format_amount("USD", -1050), "-10.50");
+    assert_eq!(format_amount("JPY", 500), "500");
+    assert_eq!(format_amount(STARS_CURRENCY, 50), "50");
+    assert_eq!(format_amount("KWD", 1234), "1.234");
+
+    let invoice = Invoice::new(
+        "Coffee".to_string(),
+        "Large".to_string(),
+        "coffee".to_string(),
+        "USD".to_string(),
+        145,
+    );
+    assert_eq!(invoice.formatted_total(), "1.45");
+}
+
+#[test]
+fn cancel_star_subscription() {
+    use telegram_bot_api::methods::{EditUserStarSubscription, Methods, Params};
+
+    let mut payment = SuccessfulPayment::new(
+        STARS_CURRENCY.to_string(),
+        100,
+        "monthly".to_string(),
+        "charge-1".to_string(),
+        String::new(),
+    );
+    assert!(payment.subscription().is_none());
+    payment.is_recurring = Some(true);
+    payment.subscription_expiration_date = Some(1720000000);
+    let subscription = payment.subscription().unwrap();
+    assert!(!subscription.is_first_recurring);
+
+    let request = EditUserStarSubscription::new(1, subscription.telegram_payment_charge_id, true);
+    assert_eq!(request.endpoint(), "editUserStarSubscription");
+    let params = request.params().unwrap();
+    assert_eq!(params["user_id"], 1);
+    assert_eq!(params["telegram_payment_charge_id"], "charge-1");
+    assert_eq!(params["is_canceled"], true);
+}
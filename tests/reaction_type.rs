@@ -0,0 +1,23 @@
+use telegram_bot_api::types::ReactionType;
+
+#[test]
+fn constructors() {
+    let reaction = ReactionType::emoji("👍");
+    assert_eq!(
+        serde_json::to_value(&reaction).unwrap(),
+        serde_json::json!({"type": "emoji", "emoji": "👍"})
+    );
+    let reaction = ReactionType::custom_emoji(String::from("5368324170671202286"));
+    assert_eq!(
+        serde_json::to_value(&reaction).unwrap(),
+        serde_json::json!({"type": "custom_emoji", "custom_emoji_id": "5368324170671202286"})
+    );
+}
+
+#[test]
+fn standard_emoji() {
+    assert!(ReactionType::is_standard_emoji("👍"));
+    assert!(ReactionType::is_standard_emoji("❤\u{200d}🔥"));
+    assert!(!ReactionType::is_standard_emoji("🦀"));
+    assert!(!ReactionType::is_standard_emoji("thumbs up"));
+}
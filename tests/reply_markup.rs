@@ -0,0 +1,93 @@
+use telegram_bot_api::types::{self, Selective};
+
+#[test]
+fn selective_sets_field() {
+    let keyboard = types::ReplyKeyboardMarkup::new(vec![]).selective(true);
+    assert_eq!(keyboard.selective, Some(true));
+    let remove = types::ReplyKeyboardRemove::new(true).selective(true);
+    assert_eq!(remove.selective, Some(true));
+    let force_reply = types::ForceReply::new(true).selective(true);
+    assert_eq!(force_reply.selective, Some(true));
+    let force_reply = force_reply.selective(false);
+    assert_eq!(force_reply.selective, Some(false));
+}
+
+fn round_trip(markup: types::ReplyMarkup) -> types::ReplyMarkup {
+    let json = serde_json::to_string(&markup).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn reply_markup_round_trips_each_variant() {
+    let inline = types::ReplyMarkup::from(types::InlineKeyboardMarkup::new(vec![]));
+    assert!(matches!(
+        round_trip(inline),
+        types::ReplyMarkup::InlineKeyboardMarkup(_)
+    ));
+
+    let keyboard = types::ReplyMarkup::from(types::ReplyKeyboardMarkup::new(vec![]));
+    assert!(matches!(
+        round_trip(keyboard),
+        types::ReplyMarkup::ReplyKeyboardMarkup(_)
+    ));
+
+    let remove = types::ReplyMarkup::from(types::ReplyKeyboardRemove::new(true).selective(true));
+    assert!(matches!(
+        round_trip(remove),
+        types::ReplyMarkup::ReplyKeyboardRemove(_)
+    ));
+
+    let force_reply = types::ReplyMarkup::from(types::ForceReply::new(true).selective(true));
+    assert!(matches!(
+        round_trip(force_reply),
+        types::ReplyMarkup::ForceReply(_)
+    ));
+}
+
+#[test]
+fn reply_markup_selective_only_is_rejected() {
+    let result = serde_json::from_str::<types::ReplyMarkup>(r#"{"selective":true}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn keyboard_button_requests_premium_user() {
+    let mut request = types::KeyboardButtonRequestUsers::new(7);
+    request.user_is_bot = Some(false);
+    request.user_is_premium = Some(true);
+    let mut button = types::KeyboardButton::new(String::from("Pick a friend"));
+    button.request_users = Some(request);
+    assert_eq!(
+        serde_json::to_value(&button).unwrap(),
+        serde_json::json!({
+            "text": "Pick a friend",
+            "request_users": {"request_id": 7, "user_is_bot": false, "user_is_premium": true}
+        })
+    );
+
+    let mut button = types::KeyboardButton::new(String::from("Pick a channel"));
+    button.request_chat = Some(types::KeyboardButtonRequestChat::new(8, true));
+    assert_eq!(
+        serde_json::to_value(&button).unwrap()["request_chat"],
+        serde_json::json!({"request_id": 8, "chat_is_channel": true})
+    );
+}
+
+#[test]
+fn chat_shared_service_message() {
+    let message: types::Message = serde_json::from_value(serde_json::json!({
+        "message_id": 20,
+        "from": {"id": 42, "is_bot": false, "first_name": "Ada"},
+        "chat": {"id": 42, "first_name": "Ada", "type": "private"},
+        "date": 1700000000,
+        "chat_shared": {"request_id": 8, "chat_id": -1001234567890_i64}
+    }))
+    .unwrap();
+    let shared = message.chat_shared.unwrap();
+    assert_eq!(shared.request_id, 8);
+    assert_eq!(shared.chat_id, -1001234567890);
+
+    let users: types::UsersShared =
+        serde_json::from_value(serde_json::json!({"request_id": 7, "user_ids": [1, 2]})).unwrap();
+    assert_eq!(users.user_ids, vec![1, 2]);
+}
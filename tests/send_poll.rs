@@ -0,0 +1,25 @@
+use telegram_bot_api::{methods, types};
+
+#[test]
+fn with_options_collects_strings() {
+    let poll = methods::SendPoll::with_options(
+        types::ChatId::IntType(1),
+        String::from("Tabs or spaces?"),
+        ["Tabs", "Spaces"],
+    );
+    assert_eq!(poll.options, vec!["Tabs", "Spaces"]);
+    assert!(poll.type_name.is_none());
+}
+
+#[test]
+fn quiz_sets_type_and_correct_option() {
+    let poll = methods::SendPoll::quiz(
+        types::ChatId::IntType(1),
+        String::from("2 + 2?"),
+        vec![String::from("3"), String::from("4")],
+        1,
+    );
+    assert_eq!(poll.type_name.as_deref(), Some("quiz"));
+    assert_eq!(poll.correct_option_id, Some(1));
+    assert_eq!(poll.options.len(), 2);
+}
@@ -0,0 +1,356 @@
+use telegram_bot_api::{methods, types};
+
+#[test]
+fn use_independent_chat_permissions() {
+    let mut request = methods::SetChatPermissions::new(
+        types::ChatId::IntType(-100),
+        types::ChatPermissions::new(),
+    );
+    let value = serde_json::to_value(&request).unwrap();
+    assert!(value.get("use_independent_chat_permissions").is_none());
+
+    request.use_independent_chat_permissions = Some(true);
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["use_independent_chat_permissions"], true);
+
+    let mut request = methods::RestrictChatMember::new(
+        types::ChatId::IntType(-100),
+        42,
+        types::ChatPermissions::new().allow_all_media(),
+    );
+    request.use_independent_chat_permissions = Some(true);
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["use_independent_chat_permissions"], true);
+    assert_eq!(value["permissions"]["can_send_photos"], true);
+}
+
+#[test]
+fn chat_action_repeat_interval() {
+    assert_eq!(
+        methods::SendChatAction::repeat_interval(),
+        std::time::Duration::from_secs(4)
+    );
+}
+
+#[test]
+fn set_sticker_emoji_list() {
+    let request = methods::SetStickerEmojiList::new(
+        String::from("sticker-id"),
+        vec![String::from("😀"), String::from("🎉")],
+    );
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["sticker"], "sticker-id");
+    assert_eq!(value["emoji_list"], serde_json::json!(["😀", "🎉"]));
+}
+
+#[test]
+fn set_sticker_keywords() {
+    let mut request = methods::SetStickerKeywords::new(String::from("sticker-id"));
+    assert!(serde_json::to_value(&request)
+        .unwrap()
+        .get("keywords")
+        .is_none());
+    request.keywords = Some(vec![String::from("party")]);
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["keywords"], serde_json::json!(["party"]));
+}
+
+#[test]
+fn set_sticker_mask_position() {
+    let mut request = methods::SetStickerMaskPosition::new(String::from("sticker-id"));
+    request.mask_position = Some(types::MaskPosition::new(
+        String::from("eyes"),
+        0.5,
+        -0.25,
+        1.0,
+    ));
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["mask_position"]["point"], "eyes");
+    assert_eq!(value["mask_position"]["y_shift"], -0.25);
+}
+
+#[test]
+fn forward_and_copy_message_thread_id() {
+    let mut forward =
+        methods::ForwardMessage::new(types::ChatId::IntType(-100), types::ChatId::IntType(1), 7);
+    assert!(serde_json::to_value(&forward)
+        .unwrap()
+        .get("message_thread_id")
+        .is_none());
+    forward.message_thread_id = Some(3);
+    assert_eq!(
+        serde_json::to_value(&forward).unwrap()["message_thread_id"],
+        3
+    );
+
+    let copy =
+        methods::CopyMessage::new(types::ChatId::IntType(-100), types::ChatId::IntType(1), 7);
+    assert!(serde_json::to_value(&copy)
+        .unwrap()
+        .get("message_thread_id")
+        .is_none());
+}
+
+#[test]
+fn business_connection_id_on_edits() {
+    fn business_connection_id<T: serde::Serialize>(request: &T) -> Option<serde_json::Value> {
+        serde_json::to_value(request)
+            .unwrap()
+            .get("business_connection_id")
+            .cloned()
+    }
+
+    let mut text = methods::EditMessageText::new(String::from("edited"));
+    assert_eq!(business_connection_id(&text), None);
+    text.business_connection_id = Some(String::from("biz-1"));
+    assert_eq!(
+        business_connection_id(&text),
+        Some(serde_json::json!("biz-1"))
+    );
+
+    let mut caption = methods::EditMessageCaption::new();
+    assert_eq!(business_connection_id(&caption), None);
+    caption.business_connection_id = Some(String::from("biz-1"));
+    assert!(business_connection_id(&caption).is_some());
+
+    let mut media = methods::EditMessageMedia::new(types::InputMedia::InputMediaPhoto(
+        types::InputMediaPhoto::new(types::InputFile::FileID(String::from("photo-id"))),
+    ));
+    assert_eq!(business_connection_id(&media), None);
+    media.business_connection_id = Some(String::from("biz-1"));
+    assert!(business_connection_id(&media).is_some());
+
+    let mut markup = methods::EditMessageReplyMarkup::new();
+    assert_eq!(business_connection_id(&markup), None);
+    markup.business_connection_id = Some(String::from("biz-1"));
+    assert!(business_connection_id(&markup).is_some());
+
+    let mut delete = methods::DeleteMessage::new(types::ChatId::IntType(1), 7);
+    assert_eq!(business_connection_id(&delete), None);
+    delete.business_connection_id = Some(String::from("biz-1"));
+    assert!(business_connection_id(&delete).is_some());
+}
+
+#[test]
+fn edit_message_media_uploads_through_attach() {
+    use methods::Methods;
+
+    let request = methods::EditMessageMedia::new(types::InputMedia::InputMediaPhoto(
+        types::InputMediaPhoto::new(types::InputFile::FilePath(String::from("cat.jpg"))),
+    ));
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["media"]["type"], "photo");
+    assert_eq!(value["media"]["media"], "attach://file-0");
+    assert!(request.files().contains_key("file-0"));
+    assert_eq!(request.endpoint(), "editMessageMedia");
+}
+
+#[test]
+fn endpoints_from_impl_method() {
+    use methods::Methods;
+
+    let request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    assert_eq!(request.endpoint(), "sendMessage");
+    assert!(request.files().is_empty());
+
+    let mut request = methods::SendAudio::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FilePath(String::from("song.mp3")),
+    );
+    assert_eq!(request.endpoint(), "sendAudio");
+    assert_eq!(request.files().len(), 1);
+    request.thumb = Some(types::InputFile::FilePath(String::from("cover.jpg")));
+    let files = request.files();
+    assert!(files.contains_key("audio"));
+    assert!(files.contains_key("thumbnail"));
+}
+
+#[test]
+fn message_effect_id() {
+    use methods::Params;
+
+    let mut request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    assert!(!request.params().unwrap().contains_key("message_effect_id"));
+    request.message_effect_id = Some(String::from("5104841245755180586"));
+    assert_eq!(
+        request.params().unwrap()["message_effect_id"],
+        "5104841245755180586"
+    );
+
+    let request = methods::SendDice::new(types::ChatId::IntType(1));
+    assert!(!request.params().unwrap().contains_key("message_effect_id"));
+}
+
+#[test]
+fn set_user_emoji_status() {
+    use methods::{Methods, Params};
+
+    let mut request = methods::SetUserEmojiStatus::new(7);
+    request.emoji_status_custom_emoji_id = Some(String::from("5368324170671202286"));
+    assert_eq!(request.endpoint(), "setUserEmojiStatus");
+    let params = request.params().unwrap();
+    assert_eq!(params["user_id"], 7);
+    assert_eq!(
+        params["emoji_status_custom_emoji_id"],
+        "5368324170671202286"
+    );
+    assert!(!params.contains_key("emoji_status_expiration_date"));
+}
+
+#[test]
+fn endpoint_is_static() {
+    use methods::Methods;
+
+    let endpoint: &'static str = {
+        let request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+        request.endpoint()
+    };
+    assert_eq!(endpoint, "sendMessage");
+}
+
+#[test]
+fn sorted_params_are_reproducible() {
+    use methods::Params;
+
+    let mut a = types::Params::new();
+    let mut b = types::Params::new();
+    for key in ["text", "chat_id", "parse_mode", "disable_notification"] {
+        a.insert(key.to_string(), serde_json::json!(key));
+    }
+    for key in ["disable_notification", "parse_mode", "chat_id", "text"] {
+        b.insert(key.to_string(), serde_json::json!(key));
+    }
+    let sorted = types::sorted_params(&a);
+    assert_eq!(sorted, types::sorted_params(&b));
+    let keys: Vec<&str> = sorted.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(
+        keys,
+        vec!["chat_id", "disable_notification", "parse_mode", "text"]
+    );
+
+    let request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    assert_eq!(
+        types::sorted_params(&request.params().unwrap()),
+        types::sorted_params(&request.params().unwrap())
+    );
+}
+
+#[test]
+fn edit_general_forum_topic() {
+    let request = methods::EditGeneralForumTopic::new(
+        types::ChatId::StringType(String::from("@forum")),
+        String::from("General chat"),
+    );
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({"chat_id": "@forum", "name": "General chat"})
+    );
+}
+
+#[test]
+fn forum_topic_icon_stickers() {
+    let stickers: Vec<types::Sticker> = serde_json::from_value(serde_json::json!([
+        {
+            "file_id": "CAACAgIAAxUAAWWtopicicon1",
+            "file_unique_id": "AgADtopicicon1",
+            "type": "custom_emoji",
+            "width": 512,
+            "height": 512,
+            "is_animated": true,
+            "is_video": false,
+            "emoji": "📰",
+            "set_name": "Topics",
+            "custom_emoji_id": "5434144690511290129"
+        },
+        {
+            "file_id": "CAACAgIAAxUAAWWtopicicon2",
+            "file_unique_id": "AgADtopicicon2",
+            "type": "custom_emoji",
+            "width": 512,
+            "height": 512,
+            "is_animated": true,
+            "is_video": false,
+            "emoji": "💡",
+            "set_name": "Topics",
+            "custom_emoji_id": "5312536423851630001"
+        }
+    ]))
+    .unwrap();
+    assert_eq!(stickers.len(), 2);
+    assert!(stickers
+        .iter()
+        .all(|sticker| sticker.type_name == "custom_emoji"));
+    assert_eq!(
+        stickers[1].custom_emoji_id.as_deref(),
+        Some("5312536423851630001")
+    );
+}
+
+#[test]
+fn set_my_name_language_code() {
+    let mut request = methods::SetMyName::new();
+    request.name = Some(String::from("Helper"));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({"name": "Helper"})
+    );
+
+    request.language_code = Some(String::from("de"));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({"name": "Helper", "language_code": "de"})
+    );
+
+    let request = methods::GetMyShortDescription::new();
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn message_options_setters() {
+    use methods::MessageOptions;
+
+    let request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    assert_eq!(request.disable_notification, None);
+    let request = request.silent();
+    assert_eq!(request.disable_notification, Some(true));
+
+    let request = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("photo-id")),
+    )
+    .silent()
+    .protect()
+    .reply_to(7)
+    .markup(types::ForceReply::new(true));
+    assert_eq!(request.disable_notification, Some(true));
+    assert_eq!(request.protect_content, Some(true));
+    assert_eq!(request.reply_to_message_id, Some(7));
+    assert!(matches!(
+        request.reply_markup,
+        Some(types::ReplyMarkup::ForceReply(_))
+    ));
+}
+
+#[test]
+fn method_response_types() {
+    fn response<M: methods::Methods>(_: &M) -> Option<M::Response> {
+        None
+    }
+
+    let send = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    let _: Option<types::Message> = response(&send);
+    let _: Option<<methods::SendMessage as methods::Methods>::Response> = None::<types::Message>;
+    let copy = methods::CopyMessage::new(types::ChatId::IntType(1), types::ChatId::IntType(2), 3);
+    let _: Option<types::MessageId> = response(&copy);
+}
+
+/// send_returns_method_response only needs to compile: BotApi::send returns M::Response.
+#[cfg(feature = "reqwest-backend")]
+#[allow(dead_code)]
+async fn send_returns_method_response(bot: &telegram_bot_api::bot::BotApi) {
+    let request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    let _: Option<types::Message> = bot.send(request).await.ok();
+}
@@ -0,0 +1,29 @@
+use telegram_bot_api::methods::{self, Methods, Params};
+use telegram_bot_api::types;
+
+fn sticker(file: types::InputFile) -> types::InputSticker {
+    types::InputSticker::new(file, String::from("static"), vec![String::from("😀")])
+}
+
+#[test]
+fn create_new_sticker_set_files() {
+    let request = methods::CreateNewStickerSet::new(
+        42,
+        String::from("pack_by_bot"),
+        String::from("Pack"),
+        vec![
+            sticker(types::InputFile::FilePath(String::from("a.png"))),
+            sticker(types::InputFile::FilePath(String::from("b.png"))),
+            sticker(types::InputFile::FileID(String::from("existing-id"))),
+        ],
+    );
+    let files = request.files();
+    assert_eq!(files.len(), 2);
+    assert!(matches!(&files["sticker-0"], types::InputFile::FilePath(p) if p == "a.png"));
+    assert!(matches!(&files["sticker-1"], types::InputFile::FilePath(p) if p == "b.png"));
+
+    let params = request.params().unwrap();
+    assert_eq!(params["stickers"][0]["sticker"], "attach://sticker-0");
+    assert_eq!(params["stickers"][1]["sticker"], "attach://sticker-1");
+    assert_eq!(params["stickers"][2]["sticker"], "existing-id");
+}
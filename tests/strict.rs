@@ -0,0 +1,26 @@
+use serde_json::json;
+use telegram_bot_api::types;
+
+fn user_with_unknown_field() -> serde_json::Value {
+    json!({
+        "id": 42,
+        "is_bot": false,
+        "first_name": "Ada",
+        "field_from_the_future": true
+    })
+}
+
+#[cfg(feature = "strict")]
+#[test]
+fn unknown_field_is_rejected() {
+    let result = serde_json::from_value::<types::User>(user_with_unknown_field());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("field_from_the_future"), "{}", err);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn unknown_field_is_ignored() {
+    let user: types::User = serde_json::from_value(user_with_unknown_field()).unwrap();
+    assert_eq!(user.id, 42);
+}
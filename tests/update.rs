@@ -0,0 +1,77 @@
+use telegram_bot_api::types;
+
+fn message(message_id: i64) -> types::Message {
+    types::Message::new(
+        message_id,
+        1700000000,
+        Box::new(types::Chat::new_super_group(-100)),
+    )
+}
+
+#[test]
+fn with_edited_message_sets_only_that_field() {
+    let update = types::Update::new(10).with_edited_message(message(5));
+    assert_eq!(update.update_id, 10);
+    assert_eq!(
+        update.edited_message.as_ref().map(|m| m.message_id),
+        Some(5)
+    );
+    assert!(update.message.is_none());
+    assert!(update.channel_post.is_none());
+    assert!(update.edited_channel_post.is_none());
+    assert!(update.callback_query.is_none());
+    assert!(update.inline_query.is_none());
+
+    let value = serde_json::to_value(&update).unwrap();
+    let keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+    assert_eq!(keys, vec!["edited_message", "update_id"]);
+}
+
+/// WithMessage is one of the Update setters that take a message.
+type WithMessage = fn(types::Update, types::Message) -> types::Update;
+
+#[test]
+fn effective_message_per_update_kind() {
+    let kinds: [(&str, WithMessage); 4] = [
+        ("message", types::Update::with_message),
+        ("edited_message", types::Update::with_edited_message),
+        ("channel_post", types::Update::with_channel_post),
+        (
+            "edited_channel_post",
+            types::Update::with_edited_channel_post,
+        ),
+    ];
+    for (id, (kind, with)) in kinds.into_iter().enumerate() {
+        let update = with(types::Update::new(1), message(id as i64));
+        assert_eq!(
+            update.effective_message().map(|m| m.message_id),
+            Some(id as i64),
+            "{}",
+            kind
+        );
+    }
+}
+
+#[test]
+fn effective_message_from_callback_query() {
+    let mut query = types::CallbackQuery::new(
+        String::from("query-1"),
+        types::User::new(42, false, String::from("Ada")),
+        String::from("instance"),
+    );
+    let update = types::Update::new(1).with_callback_query(query.clone());
+    assert!(update.effective_message().is_none());
+
+    query.message = Some(message(9));
+    let update = types::Update::new(1).with_callback_query(query);
+    assert_eq!(update.effective_message().map(|m| m.message_id), Some(9));
+}
+
+#[test]
+fn effective_message_prefers_message() {
+    let update = types::Update::new(1)
+        .with_edited_channel_post(message(2))
+        .with_message(message(1));
+    assert_eq!(update.effective_message().map(|m| m.message_id), Some(1));
+    assert!(types::Update::new(1).effective_message().is_none());
+}
@@ -0,0 +1,51 @@
+use telegram_bot_api::{methods, types};
+
+fn updates(ids: &[i64]) -> Vec<types::Update> {
+    ids.iter().map(|id| types::Update::new(*id)).collect()
+}
+
+fn ids(updates: &[&types::Update]) -> Vec<i64> {
+    updates.iter().map(|update| update.update_id).collect()
+}
+
+#[test]
+fn three_poll_cycles() {
+    let mut state = methods::UpdatesState::new();
+    state.timeout = Some(30);
+    state.allowed_updates = Some(vec![String::from("message")]);
+
+    let request = state.next_request();
+    assert_eq!(request.offset, None);
+    assert_eq!(request.timeout, Some(30));
+    let first = updates(&[10, 11, 12]);
+    assert_eq!(ids(&state.ingest(&first)), vec![10, 11, 12]);
+    assert_eq!(state.next_request().offset, Some(13));
+
+    // a retried response repeats updates that were already handled
+    let second = updates(&[12, 13, 14]);
+    assert_eq!(ids(&state.ingest(&second)), vec![13, 14]);
+    assert_eq!(state.next_request().offset, Some(15));
+
+    let third = updates(&[]);
+    assert!(state.ingest(&third).is_empty());
+    let request = state.next_request();
+    assert_eq!(request.offset, Some(15));
+    assert_eq!(request.allowed_updates, Some(vec![String::from("message")]));
+}
+
+#[test]
+fn next_offset() {
+    assert_eq!(methods::next_offset(&updates(&[3, 7, 5])), Some(8));
+    assert_eq!(methods::next_offset(&[]), None);
+}
+
+#[test]
+fn long_poll_client_timeout() {
+    let request = methods::GetUpdates::long_poll(50);
+    assert_eq!(request.timeout, Some(50));
+    assert!(request.recommended_client_timeout() >= std::time::Duration::from_secs(55));
+    assert!(request.recommended_client_timeout() > std::time::Duration::from_secs(50));
+
+    let request = methods::GetUpdates::new();
+    assert!(request.recommended_client_timeout() >= std::time::Duration::from_secs(5));
+}
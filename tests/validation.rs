@@ -0,0 +1,157 @@
+use telegram_bot_api::{methods, types};
+
+#[test]
+fn location_heading_out_of_range() {
+    let mut location = methods::SendLocation::new(types::ChatId::IntType(1), 51.5, -0.12);
+    assert!(location.validate().is_ok());
+    for heading in [0, 361] {
+        location.heading = Some(heading);
+        let err = location.validate().unwrap_err();
+        assert_eq!(err.field, "heading");
+    }
+    location.heading = Some(360);
+    assert!(location.validate().is_ok());
+}
+
+#[test]
+fn location_live_period_too_short() {
+    let mut content = types::InputLocationMessageContent::new(51.5, -0.12);
+    content.live_period = Some(59);
+    assert_eq!(content.validate().unwrap_err().field, "live_period");
+}
+
+#[test]
+fn edit_live_location_bad_latitude() {
+    let edit = methods::EditMessageLiveLocation::new(91.0, 0.0);
+    assert_eq!(edit.validate().unwrap_err().field, "latitude");
+}
+
+#[test]
+fn caption_parse_mode_and_entities_conflict() {
+    let mut photo = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("photo-id")),
+    );
+    photo.caption = Some(String::from("hello"));
+    photo.parse_mode = Some(String::from("HTML"));
+    assert!(photo.validate().is_ok());
+    photo.caption_entities = Some(vec![types::MessageEntity::new_bold(0, 5)]);
+    assert_eq!(photo.validate().unwrap_err().field, "parse_mode");
+    photo.parse_mode = None;
+    assert!(photo.validate().is_ok());
+}
+
+#[test]
+fn caption_formatting_on_other_methods() {
+    let mut voice = methods::SendVoice::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("voice-id")),
+    );
+    voice.caption_entities = Some(vec![types::MessageEntity::new_italic(0, 1)]);
+    assert!(voice.validate().is_ok());
+    voice.parse_mode = Some(String::from("MarkdownV2"));
+    assert!(voice.validate().is_err());
+
+    let mut edit = methods::EditMessageCaption::new();
+    edit.caption = Some(String::from("edited"));
+    edit.parse_mode = Some(String::from("HTML"));
+    assert!(edit.validate().is_ok());
+    edit.caption_entities = Some(vec![types::MessageEntity::new_bold(0, 6)]);
+    assert_eq!(edit.validate().unwrap_err().field, "parse_mode");
+}
+
+#[test]
+fn input_field_placeholder_length() {
+    let placeholder = "a".repeat(64);
+    let reply = types::ForceReply::new(true)
+        .with_placeholder(placeholder.clone())
+        .unwrap();
+    assert_eq!(reply.input_field_placeholder, Some(placeholder.clone()));
+    assert!(types::ReplyKeyboardMarkup::new(vec![])
+        .with_placeholder(placeholder)
+        .is_ok());
+
+    let err = types::ForceReply::new(true)
+        .with_placeholder("a".repeat(65))
+        .unwrap_err();
+    assert_eq!(err.field, "input_field_placeholder");
+    assert!(types::ReplyKeyboardMarkup::new(vec![])
+        .with_placeholder("a".repeat(65))
+        .is_err());
+    assert!(types::ForceReply::new(true)
+        .with_placeholder(String::new())
+        .is_err());
+}
+
+#[test]
+fn oversized_byte_thumb_rejected() {
+    let mut video_note = methods::SendVideoNote::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("video-note-id")),
+    );
+    assert!(video_note.validate().is_ok());
+    video_note.thumb = Some(types::InputFile::FileBytes(
+        String::from("thumb.jpg"),
+        vec![0; types::MAX_THUMBNAIL_SIZE + 1],
+    ));
+    assert_eq!(video_note.validate().unwrap_err().field, "thumb");
+
+    let mut document = methods::SendDocument::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("document-id")),
+    );
+    document.thumb = video_note.thumb.clone();
+    assert_eq!(document.validate().unwrap_err().field, "thumb");
+    document.thumb = Some(types::InputFile::FileBytes(
+        String::from("thumb.jpg"),
+        vec![0; types::MAX_THUMBNAIL_SIZE],
+    ));
+    assert!(document.validate().is_ok());
+}
+
+#[test]
+fn webhook_secret_token() {
+    let mut webhook = methods::SetWebhook::new(String::from("https://example.com/hook"));
+    assert!(webhook.validate().is_ok());
+    webhook.secret_token = Some(String::from("Abc_123-xyz"));
+    assert!(webhook.validate().is_ok());
+    webhook.secret_token = Some("a".repeat(256));
+    assert!(webhook.validate().is_ok());
+
+    for token in [
+        String::from("bad token"),
+        String::from("päss"),
+        String::new(),
+        "a".repeat(257),
+    ] {
+        webhook.secret_token = Some(token);
+        assert_eq!(webhook.validate().unwrap_err().field, "secret_token");
+    }
+
+    webhook.secret_token = Some("é".repeat(200));
+    let err = webhook.validate().unwrap_err();
+    assert!(err.message.contains("only A-Z"), "{}", err.message);
+}
+
+#[test]
+fn photo_bytes_over_size_limit() {
+    let mut photo = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileBytes(String::from("big.jpg"), vec![0; 11 * 1024 * 1024]),
+    );
+    let err = photo.validate_local().unwrap_err();
+    assert_eq!(err.field, "photo");
+    assert!(
+        err.message.contains("at most 10485760 bytes"),
+        "{}",
+        err.message
+    );
+
+    photo.photo = types::InputFile::FileBytes(
+        String::from("ok.jpg"),
+        vec![0; methods::SendPhoto::MAX_PHOTO_SIZE],
+    );
+    assert!(photo.validate_local().is_ok());
+    photo.photo = types::InputFile::FilePath(String::from("big.jpg"));
+    assert!(photo.validate_local().is_ok());
+}
@@ -0,0 +1,45 @@
+use telegram_bot_api::methods::{self, Methods};
+use telegram_bot_api::types;
+
+#[test]
+fn send_message_webhook_reply() {
+    let request = methods::SendMessage::new(types::ChatId::IntType(1), String::from("hi"));
+    let reply = request.as_webhook_reply().unwrap();
+    assert_eq!(reply["method"], "sendMessage");
+    assert_eq!(reply["chat_id"], 1);
+    assert_eq!(reply["text"], "hi");
+}
+
+#[test]
+fn send_photo_by_id_webhook_reply() {
+    let request = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FileID(String::from("photo-id")),
+    );
+    let reply = request.as_webhook_reply().unwrap();
+    assert_eq!(reply["method"], "sendPhoto");
+    assert_eq!(reply["photo"], "photo-id");
+}
+
+#[test]
+fn send_photo_upload_is_rejected() {
+    let request = methods::SendPhoto::new(
+        types::ChatId::IntType(1),
+        types::InputFile::FilePath(String::from("photo.png")),
+    );
+    assert!(request.as_webhook_reply().is_err());
+}
+
+#[test]
+fn verify_webhook_secret_header() {
+    let secret = "Abc_123-xyz";
+    assert!(methods::verify_webhook_secret(secret, Some("Abc_123-xyz")));
+    assert!(!methods::verify_webhook_secret(secret, Some("Abc_123-xyZ")));
+    assert!(!methods::verify_webhook_secret(secret, Some("Abc_123")));
+    assert!(!methods::verify_webhook_secret(secret, None));
+    assert!(!methods::verify_webhook_secret("", Some("")));
+    assert_eq!(
+        methods::WEBHOOK_SECRET_HEADER,
+        "X-Telegram-Bot-Api-Secret-Token"
+    );
+}